@@ -1,6 +1,3 @@
-use cosmos_sdk_proto::cosmos::bank::v1beta1::MsgSend;
-use cosmos_sdk_proto::cosmos::tx::v1beta1::{TxBody, TxRaw};
-use cosmos_sdk_proto::traits::Message;
 use cosmwasm_std::{
     entry_point, to_binary, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdError, StdResult,
     Uint128,
@@ -10,10 +7,10 @@ use cw2::set_contract_version;
 use crate::msg::{
     Cw20BalanceResponse, ExecuteMsg, GetRecipientTxsResponse, InstantiateMsg, MigrateMsg, QueryMsg,
 };
-use crate::state::{Transfer, RECIPIENT_TXS, TRANSFERS};
+use crate::state::{Transfer, PROCESSED_TXS, RECIPIENT_TXS, TRANSFERS};
 use neutron_sdk::bindings::msg::NeutronMsg;
 use neutron_sdk::bindings::query::{NeutronQuery, QueryRegisteredQueryResponse};
-use neutron_sdk::bindings::types::{Height, KVKey};
+use neutron_sdk::bindings::types::Height;
 use neutron_sdk::interchain_queries::v045::queries::{
     query_balance, query_bank_total, query_delegations, query_distribution_fee_pool,
     query_government_proposals, query_staking_validators,
@@ -26,7 +23,8 @@ use neutron_sdk::interchain_queries::{
         new_register_gov_proposal_query_msg, new_register_staking_validators_query_msg,
         new_register_transfers_query_msg,
         register_queries::new_register_wasm_contract_store_query_msg,
-        types::{COSMOS_SDK_TRANSFER_MSG_URL, RECIPIENT_FIELD},
+        tx::{decode_tx_query_result, match_tx_messages, TxMessage},
+        types::RECIPIENT_FIELD,
     },
 };
 use neutron_sdk::sudo::msg::SudoMsg;
@@ -34,6 +32,7 @@ use neutron_sdk::{NeutronError, NeutronResult};
 
 use neutron_sdk::interchain_queries::types::{
     QueryType, TransactionFilterItem, TransactionFilterOp, TransactionFilterValue,
+    UpdateInterchainQuery,
 };
 use serde_json_wasm;
 
@@ -112,12 +111,7 @@ pub fn execute(
             cw20_contract_address,
             account_address,
         ),
-        ExecuteMsg::UpdateInterchainQuery {
-            query_id,
-            new_keys,
-            new_update_period,
-            new_recipient,
-        } => update_interchain_query(query_id, new_keys, new_update_period, new_recipient),
+        ExecuteMsg::UpdateInterchainQuery(update) => update_interchain_query(update),
         ExecuteMsg::RemoveInterchainQuery { query_id } => remove_interchain_query(query_id),
     }
 }
@@ -223,22 +217,9 @@ pub fn register_cw20_balance_query(
 }
 
 pub fn update_interchain_query(
-    query_id: u64,
-    new_keys: Option<Vec<KVKey>>,
-    new_update_period: Option<u64>,
-    new_recipient: Option<String>,
+    update: UpdateInterchainQuery,
 ) -> NeutronResult<Response<NeutronMsg>> {
-    let new_filter = new_recipient.map(|recipient| {
-        vec![TransactionFilterItem {
-            field: RECIPIENT_FIELD.to_string(),
-            op: TransactionFilterOp::Eq,
-            value: TransactionFilterValue::String(recipient),
-        }]
-    });
-
-    let update_msg =
-        NeutronMsg::update_interchain_query(query_id, new_keys, new_update_period, new_filter)?;
-    Ok(Response::new().add_message(update_msg))
+    Ok(Response::new().add_message(update.into_msg()?))
 }
 
 pub fn remove_interchain_query(query_id: u64) -> NeutronResult<Response<NeutronMsg>> {
@@ -324,82 +305,77 @@ pub fn sudo_tx_query_result(
     deps: DepsMut<NeutronQuery>,
     _env: Env,
     query_id: u64,
-    _height: Height,
+    height: Height,
     data: Binary,
 ) -> NeutronResult<Response> {
-    // Decode the transaction data
-    let tx: TxRaw = TxRaw::decode(data.as_slice())?;
-    let body: TxBody = TxBody::decode(tx.body_bytes.as_slice())?;
+    // A relayer can submit the same transaction more than once. Record the transaction as
+    // processed and skip it if it has already been processed before, so the deposits are
+    // not counted twice. If the processing fails below, the record is reverted as well.
+    if !PROCESSED_TXS.record(deps.storage, query_id, &height, &data)? {
+        return Ok(Response::default());
+    }
+
+    // Decode the transaction data.
+    // Only handle up to MAX_ALLOWED_MESSAGES messages, everything else
+    // will be ignored to prevent 'out of gas' conditions.
+    // Note: in real contracts you will have to somehow save ignored
+    // data in order to handle it later.
+    let tx = decode_tx_query_result(&data, MAX_ALLOWED_MESSAGES)?;
 
     // Get the registered query by ID and retrieve the raw query string
     let registered_query: QueryRegisteredQueryResponse =
         get_registered_query(deps.as_ref(), query_id)?;
     let transactions_filter = registered_query.registered_query.transactions_filter;
 
-    #[allow(clippy::match_single_binding)]
-    // Depending of the query type, check the transaction data to see whether is satisfies
-    // the original query. If you don't write specific checks for a transaction query type,
-    // all submitted results will be treated as valid.
-    //
-    // TODO: come up with solution to determine transactions filter type
-    match registered_query.registered_query.query_type {
-        _ => {
-            // For transfer queries, query data looks like `[{"field:"transfer.recipient", "op":"eq", "value":"some_address"}]`
-            let query_data: Vec<TransactionFilterItem> =
-                serde_json_wasm::from_str(transactions_filter.as_str())?;
-
-            let recipient = query_data
-                .iter()
-                .find(|x| x.field == RECIPIENT_FIELD && x.op == TransactionFilterOp::Eq)
-                .map(|x| match &x.value {
-                    TransactionFilterValue::String(v) => v.as_str(),
-                    _ => "",
-                })
-                .unwrap_or("");
-
-            let deposits = recipient_deposits_from_tx_body(body, recipient)?;
-            // If we didn't find a Send message with the correct recipient, return an error, and
-            // this query result will be rejected by Neutron: no data will be saved to state.
-            if deposits.is_empty() {
-                return Err(NeutronError::Std(StdError::generic_err(
-                    "failed to find a matching transaction message",
-                )));
-            }
-
-            let mut stored_transfers: u64 = TRANSFERS.load(deps.storage).unwrap_or_default();
-            stored_transfers += deposits.len() as u64;
-            TRANSFERS.save(deps.storage, &stored_transfers)?;
-
-            check_deposits_size(&deposits)?;
-            let mut stored_deposits: Vec<Transfer> = RECIPIENT_TXS
-                .load(deps.storage, recipient)
-                .unwrap_or_default();
-            stored_deposits.extend(deposits);
-            RECIPIENT_TXS.save(deps.storage, recipient, &stored_deposits)?;
-            Ok(Response::new())
-        }
+    // For transfer queries, query data looks like `[{"field:"transfer.recipient", "op":"eq", "value":"some_address"}]`
+    let query_data: Vec<TransactionFilterItem> =
+        serde_json_wasm::from_str(transactions_filter.as_str())?;
+
+    // Check the transaction data to see whether it satisfies the original query. Only the
+    // messages that satisfy all the filters of the query are returned, so a relayer can't
+    // submit an arbitrary transaction as a result of the query.
+    let messages = match_tx_messages(&tx.messages, &height, &query_data)?;
+
+    let recipient = query_data
+        .iter()
+        .find(|x| x.field == RECIPIENT_FIELD && x.op == TransactionFilterOp::Eq)
+        .map(|x| match &x.value {
+            TransactionFilterValue::String(v) => v.as_str(),
+            _ => "",
+        })
+        .unwrap_or("");
+
+    let deposits = recipient_deposits_from_messages(messages, recipient);
+    // If we didn't find a Send message with the correct recipient, return an error, and
+    // this query result will be rejected by Neutron: no data will be saved to state.
+    if deposits.is_empty() {
+        return Err(NeutronError::Std(StdError::generic_err(
+            "failed to find a matching transaction message",
+        )));
     }
+
+    let mut stored_transfers: u64 = TRANSFERS.load(deps.storage).unwrap_or_default();
+    stored_transfers += deposits.len() as u64;
+    TRANSFERS.save(deps.storage, &stored_transfers)?;
+
+    check_deposits_size(&deposits)?;
+    let mut stored_deposits: Vec<Transfer> = RECIPIENT_TXS
+        .load(deps.storage, recipient)
+        .unwrap_or_default();
+    stored_deposits.extend(deposits);
+    RECIPIENT_TXS.save(deps.storage, recipient, &stored_deposits)?;
+    Ok(Response::new())
 }
 
-/// parses tx body and retrieves transactions to the given recipient.
-fn recipient_deposits_from_tx_body(
-    tx_body: TxBody,
-    recipient: &str,
-) -> NeutronResult<Vec<Transfer>> {
+/// retrieves transfers to the given recipient from the verified transaction messages.
+fn recipient_deposits_from_messages(messages: Vec<TxMessage>, recipient: &str) -> Vec<Transfer> {
     let mut deposits: Vec<Transfer> = vec![];
-    // Only handle up to MAX_ALLOWED_MESSAGES messages, everything else
-    // will be ignored to prevent 'out of gas' conditions.
-    // Note: in real contracts you will have to somehow save ignored
-    // data in order to handle it later.
-    for msg in tx_body.messages.iter().take(MAX_ALLOWED_MESSAGES) {
+    for msg in messages {
         // Skip all messages in this transaction that are not Send messages.
-        if msg.type_url != *COSMOS_SDK_TRANSFER_MSG_URL.to_string() {
-            continue;
-        }
-
-        // Parse a Send message and check that it has the required recipient.
-        let transfer_msg: MsgSend = MsgSend::decode(msg.value.as_slice())?;
-        if transfer_msg.to_address == recipient {
+        if let TxMessage::Send(transfer_msg) = msg {
+            if transfer_msg.to_address != recipient {
+                continue;
+            }
             for coin in transfer_msg.amount {
                 deposits.push(Transfer {
                     sender: transfer_msg.from_address.clone(),
@@ -410,7 +386,7 @@ fn recipient_deposits_from_tx_body(
             }
         }
     }
-    Ok(deposits)
+    deposits
 }
 
 // checks whether there are deposits that are greater then MAX_ALLOWED_TRANSFER.
@@ -1,6 +1,6 @@
 use crate::state::Transfer;
 use cosmwasm_std::Uint128;
-use neutron_sdk::bindings::types::KVKey;
+use neutron_sdk::interchain_queries::types::UpdateInterchainQuery;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
@@ -53,12 +53,7 @@ pub enum ExecuteMsg {
         cw20_contract_address: String,
         account_address: String,
     },
-    UpdateInterchainQuery {
-        query_id: u64,
-        new_keys: Option<Vec<KVKey>>,
-        new_update_period: Option<u64>,
-        new_recipient: Option<String>,
-    },
+    UpdateInterchainQuery(UpdateInterchainQuery),
     RemoveInterchainQuery {
         query_id: u64,
     },
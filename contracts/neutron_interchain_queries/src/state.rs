@@ -1,4 +1,5 @@
 use cw_storage_plus::{Item, Map};
+use neutron_sdk::interchain_queries::dedup::TxDedupLedger;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
@@ -8,6 +9,12 @@ pub type Recipient = str;
 pub const RECIPIENT_TXS: Map<&Recipient, Vec<Transfer>> = Map::new("recipient_txs");
 /// contains number of transfers to addresses observed by the contract.
 pub const TRANSFERS: Item<u64> = Item::new("transfers");
+/// contains hashes of transactions already processed by the contract to not count them twice.
+pub const PROCESSED_TXS: TxDedupLedger = TxDedupLedger::new(
+    "processed_txs",
+    "processed_txs_heights",
+    "processed_txs_floors",
+);
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 pub struct Transfer {
@@ -4,7 +4,7 @@ use std::str::FromStr;
 use super::mock_querier::mock_dependencies as dependencies;
 use crate::contract::{execute, query, sudo_tx_query_result};
 use crate::msg::{ExecuteMsg, QueryMsg};
-use crate::state::{Transfer, RECIPIENT_TXS};
+use crate::state::{Transfer, RECIPIENT_TXS, TRANSFERS};
 use crate::testing::mock_querier::WasmMockQuerier;
 use cosmos_sdk_proto::cosmos::base::v1beta1::{Coin as CosmosCoin, DecCoin as CosmosDecCoin};
 use cosmos_sdk_proto::cosmos::distribution::v1beta1::FeePool as CosmosFeePool;
@@ -784,25 +784,19 @@ fn test_sudo_tx_query_result_callback() {
     )
     .unwrap();
 
-    // ensure the callback has worked and contract's state has changed again
+    // ensure the callback has ignored the same transaction submitted again
+    // and contract's state hasn't changed
     let txs = RECIPIENT_TXS.load(&deps.storage, &watched_addr).unwrap();
     assert_eq!(
         txs,
-        Vec::from([
-            Transfer {
-                recipient: watched_addr.clone(),
-                sender: "neutron10h9stc5v6ntgeygf5xf945njqq5h32r54rf7kf".to_string(),
-                denom: "stake".to_string(),
-                amount: "10000".to_string(),
-            },
-            Transfer {
-                recipient: watched_addr,
-                sender: "neutron10h9stc5v6ntgeygf5xf945njqq5h32r54rf7kf".to_string(),
-                denom: "stake".to_string(),
-                amount: "10000".to_string(),
-            }
-        ])
+        Vec::from([Transfer {
+            recipient: watched_addr,
+            sender: "neutron10h9stc5v6ntgeygf5xf945njqq5h32r54rf7kf".to_string(),
+            denom: "stake".to_string(),
+            amount: "10000".to_string(),
+        }])
     );
+    assert_eq!(TRANSFERS.load(&deps.storage).unwrap(), 1);
 }
 
 #[test]
@@ -908,23 +902,17 @@ fn test_sudo_tx_query_result_min_height_callback() {
     )
     .unwrap();
 
-    // ensure the callback has worked and contract's state has changed again
+    // ensure the callback has ignored the same transaction submitted again
+    // and contract's state hasn't changed
     let txs = RECIPIENT_TXS.load(&deps.storage, &watched_addr).unwrap();
     assert_eq!(
         txs,
-        Vec::from([
-            Transfer {
-                recipient: watched_addr.clone(),
-                sender: "neutron10h9stc5v6ntgeygf5xf945njqq5h32r54rf7kf".to_string(),
-                denom: "stake".to_string(),
-                amount: "10000".to_string(),
-            },
-            Transfer {
-                recipient: watched_addr,
-                sender: "neutron10h9stc5v6ntgeygf5xf945njqq5h32r54rf7kf".to_string(),
-                denom: "stake".to_string(),
-                amount: "10000".to_string(),
-            }
-        ])
+        Vec::from([Transfer {
+            recipient: watched_addr,
+            sender: "neutron10h9stc5v6ntgeygf5xf945njqq5h32r54rf7kf".to_string(),
+            denom: "stake".to_string(),
+            amount: "10000".to_string(),
+        }])
     );
+    assert_eq!(TRANSFERS.load(&deps.storage).unwrap(), 1);
 }
@@ -6,8 +6,9 @@ use cosmos_sdk_proto::traits::Message;
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    to_binary, Binary, CosmosMsg, CustomQuery, Deps, DepsMut, Env, MessageInfo, Reply, Response,
-    StdError, StdResult, SubMsg,
+    from_binary, to_binary, to_vec, Binary, ContractResult, CosmosMsg, CustomQuery, Deps, DepsMut,
+    Env, MessageInfo, QueryRequest, Reply, Response, StdError, StdResult, SubMsg, SystemError,
+    SystemResult,
 };
 use cw2::set_contract_version;
 use schemars::JsonSchema;
@@ -24,6 +25,7 @@ use neutron_sdk::{
     interchain_txs::helpers::{
         decode_acknowledgement_response, decode_message_response, get_port_id,
     },
+    query::interchain_txs::InterchainTxsParamsResponse,
     query::min_ibc_fee::query_min_ibc_fee,
     sudo::msg::{RequestPacket, SudoMsg},
     NeutronError, NeutronResult,
@@ -183,14 +185,45 @@ fn msg_with_sudo_callback<C: Into<CosmosMsg<T>>, T>(
     Ok(SubMsg::reply_on_success(msg, SUDO_PAYLOAD_REPLY_ID))
 }
 
+/// Returns the fee charged for an interchain account registration,
+/// or None if the chain doesn't support the interchaintxs params query
+fn query_register_fee(deps: Deps<NeutronQuery>) -> NeutronResult<Option<Vec<cosmwasm_std::Coin>>> {
+    let request: QueryRequest<NeutronQuery> = NeutronQuery::InterchainTxsParams {}.into();
+    match deps.querier.raw_query(&to_vec(&request)?) {
+        SystemResult::Err(SystemError::UnsupportedRequest { .. }) => Ok(None),
+        SystemResult::Err(err) => {
+            Err(StdError::generic_err(format!("Querier system error: {}", err)).into())
+        }
+        SystemResult::Ok(ContractResult::Err(err)) => {
+            Err(StdError::generic_err(format!("Querier contract error: {}", err)).into())
+        }
+        SystemResult::Ok(ContractResult::Ok(value)) => Ok(Some(
+            from_binary::<InterchainTxsParamsResponse>(&value)?
+                .params
+                .register_fee,
+        )),
+    }
+}
+
 fn execute_register_ica(
     deps: DepsMut<NeutronQuery>,
     env: Env,
     connection_id: String,
     interchain_account_id: String,
 ) -> NeutronResult<Response<NeutronMsg>> {
-    let register =
-        NeutronMsg::register_interchain_account(connection_id, interchain_account_id.clone());
+    // newer Neutron versions charge a fee for the registration, it is taken from the contract balance.
+    // Older ones don't support the params query, so we register the account without a fee there
+    let register = match query_register_fee(deps.as_ref())? {
+        Some(register_fee) => NeutronMsg::register_interchain_account_with_options(
+            connection_id,
+            interchain_account_id.clone(),
+            Some(register_fee).filter(|fee| !fee.is_empty()),
+            None,
+        ),
+        None => {
+            NeutronMsg::register_interchain_account(connection_id, interchain_account_id.clone())
+        }
+    };
     let key = get_port_id(env.contract.address.as_str(), &interchain_account_id);
     // we are saving empty data here because we handle response of registering ICA in sudo_open_ack method
     INTERCHAIN_ACCOUNTS.save(deps.storage, key, &None)?;
@@ -1,17 +1,19 @@
 use std::marker::PhantomData;
 
 use crate::{
-    contract::query_errors_queue,
+    contract::{execute, query_errors_queue},
+    msg::ExecuteMsg,
     storage::{add_error_to_queue, read_errors_from_queue, ERRORS_QUEUE},
 };
 
 use cosmwasm_std::{
-    from_binary,
-    testing::{MockApi, MockQuerier, MockStorage},
-    OwnedDeps,
+    coin, from_binary,
+    testing::{mock_env, mock_info, MockApi, MockQuerier, MockStorage},
+    to_binary, ContractResult, CosmosMsg, OwnedDeps, SystemResult,
 };
 
-use neutron_sdk::bindings::query::NeutronQuery;
+use neutron_sdk::bindings::{msg::NeutronMsg, query::NeutronQuery};
+use neutron_sdk::query::interchain_txs::{InterchainTxsParams, InterchainTxsParamsResponse};
 
 pub fn mock_dependencies() -> OwnedDeps<MockStorage, MockApi, MockQuerier, NeutronQuery> {
     OwnedDeps {
@@ -78,3 +80,77 @@ fn test_errors_queue() {
         ]
     );
 }
+
+#[test]
+fn test_register_ica_fee() {
+    let register =
+        |deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier<NeutronQuery>, NeutronQuery>| {
+            let res = execute(
+                deps.as_mut(),
+                mock_env(),
+                mock_info("sender", &[]),
+                ExecuteMsg::Register {
+                    connection_id: "connection-0".to_string(),
+                    interchain_account_id: "test".to_string(),
+                },
+            )
+            .unwrap();
+            assert_eq!(res.messages.len(), 1);
+            res.messages[0].msg.clone()
+        };
+
+    // older Neutron versions don't support the params query, the account is registered without a fee
+    let mut deps = OwnedDeps {
+        storage: MockStorage::default(),
+        api: MockApi::default(),
+        querier: MockQuerier::<NeutronQuery>::new(&[]),
+        custom_query_type: PhantomData,
+    };
+    assert_eq!(
+        register(&mut deps),
+        CosmosMsg::Custom(NeutronMsg::register_interchain_account(
+            "connection-0".to_string(),
+            "test".to_string(),
+        ))
+    );
+
+    // other errors are not swallowed, otherwise a fee-less registration would be rejected by the chain
+    deps.querier = MockQuerier::<NeutronQuery>::new(&[])
+        .with_custom_handler(|_| SystemResult::Ok(ContractResult::Err("out of gas".to_string())));
+    assert_eq!(
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("sender", &[]),
+            ExecuteMsg::Register {
+                connection_id: "connection-0".to_string(),
+                interchain_account_id: "test".to_string(),
+            },
+        )
+        .unwrap_err()
+        .to_string(),
+        "Generic error: Querier contract error: out of gas"
+    );
+
+    deps.querier = MockQuerier::<NeutronQuery>::new(&[]).with_custom_handler(|query| {
+        assert_eq!(query, &NeutronQuery::InterchainTxsParams {});
+        SystemResult::Ok(ContractResult::Ok(
+            to_binary(&InterchainTxsParamsResponse {
+                params: InterchainTxsParams {
+                    msg_submit_tx_max_messages: 16,
+                    register_fee: vec![coin(1000, "untrn")],
+                },
+            })
+            .unwrap(),
+        ))
+    });
+    assert_eq!(
+        register(&mut deps),
+        CosmosMsg::Custom(NeutronMsg::register_interchain_account_with_options(
+            "connection-0".to_string(),
+            "test".to_string(),
+            Some(vec![coin(1000, "untrn")]),
+            None,
+        ))
+    );
+}
@@ -0,0 +1,278 @@
+//! Derive macros for Neutron CosmWasm SDK.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{
+    parse_macro_input, spanned::Spanned, Data, DeriveInput, Error, Fields, GenericArgument, LitInt,
+    LitStr, PathArguments, Result, Type,
+};
+
+/// Derives `KVReconstruct` for a struct which fields are values of a KV Interchain Query.
+///
+/// Every field is reconstructed from a single storage value and must be annotated with
+/// `#[kv(...)]` attribute:
+/// * `path = "..."` is a store path of the value on remote chain, e.g. `"bank"` or `"wasm"`;
+/// * `proto` or `json` describes the encoding of the value: prost messages (e.g. Cosmos-SDK module
+///   items) are decoded from protobuf, wasm contract items are decoded from JSON;
+/// * `index = N` is an optional position of the field key in the query keys. Fields are ordered
+///   by declaration if no field has an index, otherwise every field must have one.
+///
+/// `Option<T>` fields are `None` when the value is absent on remote chain, absent values of other
+/// fields are an error.
+///
+/// Besides `KVReconstruct`, the macro generates `kv_keys` associated function, which builds the
+/// query keys out of raw storage keys of the fields passed in the key order:
+/// ```rust ignore
+/// #[derive(KVReconstruct)]
+/// struct Pool {
+///     #[kv(path = "bank", proto)]
+///     balance: cosmos_sdk_proto::cosmos::base::v1beta1::Coin,
+///     #[kv(path = "wasm", json)]
+///     config: Option<Config>,
+/// }
+///
+/// let msg = NeutronMsg::register_interchain_query(
+///     QueryPayload::KV(Pool::kv_keys(balance_key, config_key)),
+///     connection_id,
+///     update_period,
+/// )?;
+/// // later on
+/// let pool: Pool = query_kv_result(deps, query_id)?;
+/// ```
+#[proc_macro_derive(KVReconstruct, attributes(kv))]
+pub fn derive_kv_reconstruct(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_kv_reconstruct(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+enum Encoding {
+    Proto,
+    Json,
+}
+
+struct KVField {
+    ident: syn::Ident,
+    /// type of the value, i.e. `T` for `Option<T>` fields
+    value_type: Type,
+    optional: bool,
+    path: LitStr,
+    encoding: Encoding,
+    index: Option<usize>,
+}
+
+fn expand_kv_reconstruct(input: DeriveInput) -> Result<TokenStream2> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(Error::new(
+                    input.span(),
+                    "KVReconstruct can only be derived for structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(Error::new(
+                input.span(),
+                "KVReconstruct can only be derived for structs",
+            ))
+        }
+    };
+
+    let mut kv_fields = fields.iter().map(parse_field).collect::<Result<Vec<_>>>()?;
+    order_fields(&mut kv_fields, &input)?;
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let count = kv_fields.len();
+
+    let reconstruct_fields = kv_fields.iter().enumerate().map(|(i, field)| {
+        let ident = &field.ident;
+        let value_type = &field.value_type;
+        let decode = match field.encoding {
+            Encoding::Proto => quote! {
+                ::neutron_sdk::interchain_queries::helpers::decode_proto_storage_value::<#value_type>(kv)?
+            },
+            Encoding::Json => quote! {
+                ::neutron_sdk::interchain_queries::helpers::decode_json_storage_value::<#value_type>(kv)?
+            },
+        };
+        let value = if field.optional {
+            quote! {
+                if kv.value.is_empty() {
+                    ::std::option::Option::None
+                } else {
+                    ::std::option::Option::Some(#decode)
+                }
+            }
+        } else {
+            let empty_error = format!("{} is empty", ident);
+            quote! {
+                if kv.value.is_empty() {
+                    return ::std::result::Result::Err(
+                        ::neutron_sdk::NeutronError::InvalidQueryResultFormat(#empty_error.into()),
+                    );
+                } else {
+                    #decode
+                }
+            }
+        };
+        quote! {
+            #ident: {
+                let kv = &kvs[#i];
+                #value
+            }
+        }
+    });
+
+    let key_args = kv_fields.iter().map(|field| {
+        let ident = &field.ident;
+        quote! { #ident: impl ::std::convert::AsRef<[u8]> }
+    });
+    let keys = kv_fields.iter().map(|field| {
+        let ident = &field.ident;
+        let path = &field.path;
+        quote! {
+            ::neutron_sdk::bindings::types::KVKey {
+                path: ::std::string::String::from(#path),
+                key: #ident.as_ref().to_vec().into(),
+            }
+        }
+    });
+
+    Ok(quote! {
+        impl #impl_generics ::neutron_sdk::interchain_queries::types::KVReconstruct
+            for #name #ty_generics #where_clause
+        {
+            fn reconstruct(
+                kvs: &[::neutron_sdk::bindings::types::StorageValue],
+            ) -> ::neutron_sdk::NeutronResult<Self> {
+                if kvs.len() != #count {
+                    return ::std::result::Result::Err(
+                        ::neutron_sdk::NeutronError::InvalidQueryResultFormat(::std::format!(
+                            "expected {} storage values, got {}",
+                            #count,
+                            kvs.len()
+                        )),
+                    );
+                }
+
+                ::std::result::Result::Ok(Self {
+                    #(#reconstruct_fields,)*
+                })
+            }
+        }
+
+        impl #impl_generics #name #ty_generics #where_clause {
+            /// Builds KV keys of the query from raw storage keys of the fields in the key order
+            #[allow(clippy::too_many_arguments)]
+            pub fn kv_keys(
+                #(#key_args),*
+            ) -> ::std::vec::Vec<::neutron_sdk::bindings::types::KVKey> {
+                ::std::vec![#(#keys),*]
+            }
+        }
+    })
+}
+
+fn parse_field(field: &syn::Field) -> Result<KVField> {
+    let ident = field.ident.clone().expect("named field");
+
+    let mut path: Option<LitStr> = None;
+    let mut encoding: Option<Encoding> = None;
+    let mut index: Option<usize> = None;
+
+    for attr in field.attrs.iter().filter(|attr| attr.path().is_ident("kv")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("path") {
+                path = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("proto") {
+                encoding = Some(Encoding::Proto);
+            } else if meta.path.is_ident("json") {
+                encoding = Some(Encoding::Json);
+            } else if meta.path.is_ident("index") {
+                index = Some(meta.value()?.parse::<LitInt>()?.base10_parse()?);
+            } else {
+                return Err(meta.error("unsupported kv attribute"));
+            }
+            Ok(())
+        })?;
+    }
+
+    let path = path.ok_or_else(|| {
+        Error::new(
+            field.span(),
+            "missing store path, add #[kv(path = \"...\")] attribute",
+        )
+    })?;
+    let encoding = encoding.ok_or_else(|| {
+        Error::new(
+            field.span(),
+            "missing value encoding, add `proto` or `json` to #[kv(...)] attribute",
+        )
+    })?;
+
+    let (value_type, optional) = match option_inner_type(&field.ty) {
+        Some(inner) => (inner.clone(), true),
+        None => (field.ty.clone(), false),
+    };
+
+    Ok(KVField {
+        ident,
+        value_type,
+        optional,
+        path,
+        encoding,
+        index,
+    })
+}
+
+/// Sorts **fields** by their indices, checking the indices are either all absent or a permutation
+fn order_fields(fields: &mut [KVField], input: &DeriveInput) -> Result<()> {
+    let indexed = fields.iter().filter(|field| field.index.is_some()).count();
+    if indexed == 0 {
+        return Ok(());
+    }
+    if indexed != fields.len() {
+        return Err(Error::new(
+            input.span(),
+            "either all fields or none of them must have an index",
+        ));
+    }
+
+    fields.sort_by_key(|field| field.index);
+    for (i, field) in fields.iter().enumerate() {
+        if field.index != Some(i) {
+            return Err(Error::new(
+                field.ident.span(),
+                format!(
+                    "field indices must be unique and go from 0 to {}",
+                    fields.len() - 1
+                ),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns `T` if **ty** is `Option<T>`
+fn option_inner_type(ty: &Type) -> Option<&Type> {
+    let Type::Path(type_path) = ty else {
+        return None;
+    };
+    let segment = type_path.path.segments.last()?;
+    if segment.ident != "Option" {
+        return None;
+    }
+    let PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+    match args.args.first()? {
+        GenericArgument::Type(inner) => Some(inner),
+        _ => None,
+    }
+}
@@ -0,0 +1,141 @@
+use cosmos_sdk_proto::cosmos::base::v1beta1::Coin as CosmosCoin;
+use cosmos_sdk_proto::cosmos::staking::v1beta1::Delegation;
+use cosmos_sdk_proto::traits::Message;
+use cosmwasm_std::{to_binary, Binary};
+use neutron_sdk::bindings::types::{KVKey, StorageValue};
+use neutron_sdk::interchain_queries::types::KVReconstruct;
+use neutron_sdk::NeutronError;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Config {
+    owner: String,
+    fee: u64,
+}
+
+#[derive(KVReconstruct, Debug, PartialEq)]
+struct Pool {
+    #[kv(path = "bank", proto)]
+    balance: CosmosCoin,
+    #[kv(path = "wasm", json)]
+    config: Config,
+    #[kv(path = "staking", proto)]
+    delegation: Option<Delegation>,
+}
+
+#[derive(KVReconstruct, Debug, PartialEq)]
+struct Reordered {
+    #[kv(path = "wasm", json, index = 1)]
+    config: Config,
+    #[kv(path = "bank", proto, index = 0)]
+    balance: CosmosCoin,
+}
+
+fn storage_value(path: &str, key: &[u8], value: Binary) -> StorageValue {
+    StorageValue {
+        storage_prefix: path.to_string(),
+        key: Binary::from(key),
+        value,
+    }
+}
+
+fn build_balance() -> CosmosCoin {
+    CosmosCoin {
+        denom: "uatom".to_string(),
+        amount: "100".to_string(),
+    }
+}
+
+fn build_config() -> Config {
+    Config {
+        owner: "owner".to_string(),
+        fee: 10,
+    }
+}
+
+#[test]
+fn test_derive_kv_reconstruct() {
+    let delegation = Delegation {
+        delegator_address: "delegator".to_string(),
+        validator_address: "validator".to_string(),
+        shares: "1000".to_string(),
+    };
+    let mut kvs = vec![
+        storage_value("bank", b"balance", Binary(build_balance().encode_to_vec())),
+        storage_value("wasm", b"config", to_binary(&build_config()).unwrap()),
+        storage_value("staking", b"delegation", Binary(delegation.encode_to_vec())),
+    ];
+
+    assert_eq!(
+        Pool::reconstruct(&kvs).unwrap(),
+        Pool {
+            balance: build_balance(),
+            config: build_config(),
+            delegation: Some(delegation),
+        }
+    );
+
+    // absent optional values are reconstructed as None
+    kvs[2].value = Binary::default();
+    assert_eq!(Pool::reconstruct(&kvs).unwrap().delegation, None);
+
+    // absent required values are errors
+    kvs[1].value = Binary::default();
+    assert_eq!(
+        Pool::reconstruct(&kvs).unwrap_err(),
+        NeutronError::InvalidQueryResultFormat("config is empty".to_string())
+    );
+
+    assert_eq!(
+        Pool::reconstruct(&kvs[..2]).unwrap_err(),
+        NeutronError::InvalidQueryResultFormat("expected 3 storage values, got 2".to_string())
+    );
+}
+
+#[test]
+fn test_derive_kv_keys() {
+    assert_eq!(
+        Pool::kv_keys(b"balance", String::from("config"), [1u8, 2u8]),
+        vec![
+            KVKey {
+                path: "bank".to_string(),
+                key: Binary::from(b"balance".as_slice()),
+            },
+            KVKey {
+                path: "wasm".to_string(),
+                key: Binary::from(b"config".as_slice()),
+            },
+            KVKey {
+                path: "staking".to_string(),
+                key: Binary::from(vec![1u8, 2u8]),
+            },
+        ]
+    );
+
+    // keys follow the field indices
+    assert_eq!(
+        Reordered::kv_keys(b"balance", b"config"),
+        vec![
+            KVKey {
+                path: "bank".to_string(),
+                key: Binary::from(b"balance".as_slice()),
+            },
+            KVKey {
+                path: "wasm".to_string(),
+                key: Binary::from(b"config".as_slice()),
+            },
+        ]
+    );
+
+    let kvs = vec![
+        storage_value("bank", b"balance", Binary(build_balance().encode_to_vec())),
+        storage_value("wasm", b"config", to_binary(&build_config()).unwrap()),
+    ];
+    assert_eq!(
+        Reordered::reconstruct(&kvs).unwrap(),
+        Reordered {
+            config: build_config(),
+            balance: build_balance(),
+        }
+    );
+}
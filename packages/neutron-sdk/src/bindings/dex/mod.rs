@@ -0,0 +1,3 @@
+pub mod msg;
+pub mod query;
+pub mod types;
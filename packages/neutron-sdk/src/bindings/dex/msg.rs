@@ -0,0 +1,120 @@
+use crate::bindings::dex::types::{DepositOption, LimitOrderType, MultiHopRoute, PrecDec};
+use cosmwasm_std::{Coin, Uint128};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+/// Messages to interact with Neutron's DEX (duality) module.
+pub enum DexMsg {
+    /// Deposit provides liquidity to pools of the **token_a**/**token_b** pair.
+    /// The vectors describe the positions: i-th elements of all of them belong to the same pool.
+    Deposit {
+        /// **receiver** is an address which receives pool shares.
+        receiver: String,
+        /// **token_a** is a denom of token A.
+        token_a: String,
+        /// **token_b** is a denom of token B.
+        token_b: String,
+        /// **amounts_a** are amounts of token A to deposit.
+        amounts_a: Vec<Uint128>,
+        /// **amounts_b** are amounts of token B to deposit.
+        amounts_b: Vec<Uint128>,
+        /// **tick_indexes_a_to_b** are tick indexes of pool centers in A to B direction.
+        tick_indexes_a_to_b: Vec<i64>,
+        /// **fees** are fee tiers of the pools.
+        fees: Vec<u64>,
+        /// **options** are options of the deposits.
+        options: Vec<DepositOption>,
+    },
+    /// Withdrawal removes liquidity from pools of the **token_a**/**token_b** pair.
+    Withdrawal {
+        /// **receiver** is an address which receives the withdrawn tokens.
+        receiver: String,
+        /// **token_a** is a denom of token A.
+        token_a: String,
+        /// **token_b** is a denom of token B.
+        token_b: String,
+        /// **shares_to_remove** are amounts of pool shares to remove.
+        shares_to_remove: Vec<Uint128>,
+        /// **tick_indexes_a_to_b** are tick indexes of pool centers in A to B direction.
+        tick_indexes_a_to_b: Vec<i64>,
+        /// **fees** are fee tiers of the pools.
+        fees: Vec<u64>,
+    },
+    /// PlaceLimitOrder swaps **token_in** for **token_out** and/or places a limit order on the book.
+    PlaceLimitOrder {
+        /// **receiver** is an address which receives the swapped tokens.
+        receiver: String,
+        /// **token_in** is a denom of the sold token.
+        token_in: String,
+        /// **token_out** is a denom of the bought token.
+        token_out: String,
+        /// **tick_index_in_to_out** is a limit price tick in **token_in** to **token_out** direction.
+        tick_index_in_to_out: i64,
+        /// **amount_in** is an amount of **token_in** to sell.
+        amount_in: Uint128,
+        /// **order_type** defines how the order behaves when it can't be filled immediately.
+        order_type: LimitOrderType,
+        /// **expiration_time** is a unix timestamp in seconds, required for `GOOD_TIL_TIME` orders only.
+        expiration_time: Option<u64>,
+        /// **max_amount_out** is a maximum amount of **token_out** to buy.
+        max_amount_out: Option<Uint128>,
+    },
+    /// WithdrawFilledLimitOrder withdraws the filled part of a limit order.
+    WithdrawFilledLimitOrder {
+        /// **tranche_key** is a key of the tranche the order belongs to.
+        tranche_key: String,
+    },
+    /// CancelLimitOrder cancels the unfilled part of a limit order and withdraws the filled part.
+    CancelLimitOrder {
+        /// **tranche_key** is a key of the tranche the order belongs to.
+        tranche_key: String,
+    },
+    /// MultiHopSwap swaps tokens through one of the **routes**.
+    MultiHopSwap {
+        /// **receiver** is an address which receives the swapped tokens.
+        receiver: String,
+        /// **routes** are routes to swap through, all of them must start and end with the same denoms.
+        routes: Vec<MultiHopRoute>,
+        /// **amount_in** is an amount of the first denom of the routes to sell.
+        amount_in: Uint128,
+        /// **exit_limit_price** is a minimum price of the whole swap.
+        exit_limit_price: PrecDec,
+        /// **pick_best_route** makes the module try all the routes and pick the best one,
+        /// otherwise the first route which satisfies **exit_limit_price** is used.
+        pick_best_route: bool,
+    },
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+/// Describes response structure for **Deposit** msg.
+pub struct MsgDepositResponse {
+    pub reserve0_deposited: Vec<Uint128>,
+    pub reserve1_deposited: Vec<Uint128>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+/// Describes response structure for **Withdrawal** msg.
+pub struct MsgWithdrawalResponse {}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+/// Describes response structure for **PlaceLimitOrder** msg.
+pub struct MsgPlaceLimitOrderResponse {
+    /// **tranche_key** is a key of the tranche the order is placed into.
+    pub tranche_key: String,
+    /// **coin_in** is the total amount of coins used for the order.
+    pub coin_in: Coin,
+    /// **taker_coin_out** is the amount of coins bought by the swap part of the order.
+    pub taker_coin_out: Coin,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+/// Describes response structure for **MultiHopSwap** msg.
+pub struct MsgMultiHopSwapResponse {
+    pub coin_out: Coin,
+}
@@ -0,0 +1,184 @@
+use crate::bindings::dex::types::{
+    DepositRecord, LimitOrderTranche, LimitOrderTrancheUser, LimitOrderType, MultiHopRoute, Params,
+    Pool, PoolReserves, PrecDec, TickLiquidity,
+};
+use crate::bindings::query::{PageRequest, PageResponse};
+use cosmwasm_std::{Coin, Uint128};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+/// Queries of Neutron's DEX (duality) module.
+/// Pairs are identified by **pair_id** strings, e.g. `untrn<>uatom`.
+pub enum DexQuery {
+    /// Parameters of the module.
+    Params {},
+    /// Pool of the pair with a center at **tick_index** and a **fee** tier.
+    Pool {
+        pair_id: String,
+        tick_index: i64,
+        fee: u64,
+    },
+    /// Pool by its identifier.
+    #[serde(rename = "pool_by_id")]
+    PoolByID { pool_id: u64 },
+    /// Reserves of a pool on the **token_in** side of the tick.
+    PoolReserves {
+        pair_id: String,
+        token_in: String,
+        tick_index: i64,
+        fee: u64,
+    },
+    /// All pool reserves of the pair on the **token_in** side.
+    PoolReservesAll {
+        pair_id: String,
+        token_in: String,
+        pagination: Option<PageRequest>,
+    },
+    /// All liquidity (pool reserves and limit order tranches) of the pair on the **token_in** side.
+    TickLiquidityAll {
+        pair_id: String,
+        token_in: String,
+        pagination: Option<PageRequest>,
+    },
+    /// Limit order tranche at **tick_index**.
+    LimitOrderTranche {
+        pair_id: String,
+        tick_index: i64,
+        token_in: String,
+        tranche_key: String,
+    },
+    /// All limit order tranches of the pair on the **token_in** side.
+    LimitOrderTrancheAll {
+        pair_id: String,
+        token_in: String,
+        pagination: Option<PageRequest>,
+    },
+    /// Limit order of the **address** in a tranche.
+    LimitOrderTrancheUser {
+        address: String,
+        tranche_key: String,
+    },
+    /// All limit orders of the **address**.
+    LimitOrderTrancheUserAllByAddress {
+        address: String,
+        pagination: Option<PageRequest>,
+    },
+    /// All liquidity positions of the **address**.
+    UserDepositAll {
+        address: String,
+        include_pool_data: bool,
+        pagination: Option<PageRequest>,
+    },
+    /// Estimates the result of a `DexMsg::MultiHopSwap` sent by **creator**.
+    EstimateMultiHopSwap {
+        creator: String,
+        receiver: String,
+        routes: Vec<MultiHopRoute>,
+        amount_in: Uint128,
+        exit_limit_price: PrecDec,
+        pick_best_route: bool,
+    },
+    /// Estimates the result of a `DexMsg::PlaceLimitOrder` sent by **creator**.
+    EstimatePlaceLimitOrder {
+        creator: String,
+        receiver: String,
+        token_in: String,
+        token_out: String,
+        tick_index_in_to_out: i64,
+        amount_in: Uint128,
+        order_type: LimitOrderType,
+        expiration_time: Option<u64>,
+        max_amount_out: Option<Uint128>,
+    },
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct ParamsResponse {
+    pub params: Params,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct PoolResponse {
+    pub pool: Pool,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct PoolReservesResponse {
+    pub pool_reserves: PoolReserves,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct AllPoolReservesResponse {
+    pub pool_reserves: Vec<PoolReserves>,
+    #[serde(default)]
+    pub pagination: Option<PageResponse>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct AllTickLiquidityResponse {
+    pub tick_liquidity: Vec<TickLiquidity>,
+    #[serde(default)]
+    pub pagination: Option<PageResponse>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct LimitOrderTrancheResponse {
+    #[serde(default)]
+    pub limit_order_tranche: Option<LimitOrderTranche>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct AllLimitOrderTrancheResponse {
+    pub limit_order_tranche: Vec<LimitOrderTranche>,
+    #[serde(default)]
+    pub pagination: Option<PageResponse>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct LimitOrderTrancheUserResponse {
+    #[serde(default)]
+    pub limit_order_tranche_user: Option<LimitOrderTrancheUser>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct AllUserLimitOrdersResponse {
+    pub limit_orders: Vec<LimitOrderTrancheUser>,
+    #[serde(default)]
+    pub pagination: Option<PageResponse>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct AllUserDepositsResponse {
+    pub deposits: Vec<DepositRecord>,
+    #[serde(default)]
+    pub pagination: Option<PageResponse>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct EstimateMultiHopSwapResponse {
+    pub coin_out: Coin,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct EstimatePlaceLimitOrderResponse {
+    /// **total_in_coin** is the total amount of coins used for the order.
+    pub total_in_coin: Coin,
+    /// **swap_in_coin** is the amount of coins swapped immediately.
+    pub swap_in_coin: Coin,
+    /// **swap_out_coin** is the amount of coins received from the swap.
+    pub swap_out_coin: Coin,
+}
@@ -0,0 +1,181 @@
+use cosmwasm_std::{Int128, Uint128};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+/// LimitOrderType defines how a limit order behaves when it can't be filled immediately.
+pub enum LimitOrderType {
+    /// **GOOD_TIL_CANCELLED** order is swapped as much as possible, the rest is placed on the book
+    /// until it is filled or cancelled.
+    #[default]
+    GoodTilCancelled,
+    /// **FILL_OR_KILL** order is swapped in full or fails.
+    FillOrKill,
+    /// **IMMEDIATE_OR_CANCEL** order is swapped as much as possible, the rest is cancelled.
+    ImmediateOrCancel,
+    /// **JUST_IN_TIME** order is placed on the book and is available for a single block only.
+    JustInTime,
+    /// **GOOD_TIL_TIME** order is placed on the book until **expiration_time**.
+    GoodTilTime,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+/// DepositOption defines options of a single deposit position.
+pub struct DepositOption {
+    /// **disable_autoswap** disables swapping of the deposit to the current price of the pool.
+    pub disable_autoswap: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+/// DepositPosition describes liquidity deposited into a single pool.
+pub struct DepositPosition {
+    /// **amount_a** is an amount of token A to deposit.
+    pub amount_a: Uint128,
+    /// **amount_b** is an amount of token B to deposit.
+    pub amount_b: Uint128,
+    /// **tick_index_a_to_b** is a tick index of the pool center in A to B direction.
+    pub tick_index_a_to_b: i64,
+    /// **fee** is a fee tier of the pool, the pool spans from `tick - fee` to `tick + fee`.
+    pub fee: u64,
+    /// **options** are options of the deposit.
+    pub options: DepositOption,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+/// WithdrawalPosition describes liquidity withdrawn from a single pool.
+pub struct WithdrawalPosition {
+    /// **shares** is an amount of pool shares to remove.
+    pub shares: Uint128,
+    /// **tick_index_a_to_b** is a tick index of the pool center in A to B direction.
+    pub tick_index_a_to_b: i64,
+    /// **fee** is a fee tier of the pool.
+    pub fee: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+/// MultiHopRoute is a list of denoms to swap through, starting with the denom being swapped in.
+pub struct MultiHopRoute {
+    pub hops: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq, JsonSchema)]
+#[serde(transparent)]
+/// PrecDec is a high precision decimal used by the DEX for prices, e.g. `"1.000100000000000000000000000"`.
+pub struct PrecDec(pub String);
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+/// PairID is an identifier of a pair of tokens, the denoms are sorted.
+pub struct PairID {
+    pub token0: String,
+    pub token1: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+/// TradePairID is an identifier of a pair of tokens in a trade direction.
+pub struct TradePairID {
+    pub maker_denom: String,
+    pub taker_denom: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct Params {
+    /// **fee_tiers** is a list of allowed pool fees.
+    pub fee_tiers: Vec<u64>,
+    /// **max_true_taker_spread** is the maximum allowed spread of a taker swap.
+    #[serde(default)]
+    pub max_true_taker_spread: PrecDec,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct PoolReservesKey {
+    pub trade_pair_id: TradePairID,
+    pub tick_index_taker_to_maker: i64,
+    pub fee: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+/// PoolReserves is liquidity of a pool on one side of the tick.
+pub struct PoolReserves {
+    pub key: PoolReservesKey,
+    pub reserves_maker_denom: Int128,
+    pub price_taker_to_maker: PrecDec,
+    pub price_opposite_taker_to_maker: PrecDec,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+/// Pool is a liquidity pool spanning two ticks.
+pub struct Pool {
+    pub id: u64,
+    pub lower_tick0: Option<PoolReserves>,
+    pub upper_tick1: Option<PoolReserves>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct LimitOrderTrancheKey {
+    pub trade_pair_id: TradePairID,
+    pub tick_index_taker_to_maker: i64,
+    pub tranche_key: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+/// LimitOrderTranche is a group of limit orders placed at the same tick in the same block.
+pub struct LimitOrderTranche {
+    pub key: LimitOrderTrancheKey,
+    pub reserves_maker_denom: Int128,
+    pub reserves_taker_denom: Int128,
+    pub total_maker_denom: Int128,
+    pub total_taker_denom: Int128,
+    /// **expiration_time** is a unix timestamp in seconds for `GOOD_TIL_TIME` and `JUST_IN_TIME` orders.
+    pub expiration_time: Option<u64>,
+    pub price_taker_to_maker: PrecDec,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+/// LimitOrderTrancheUser is a share of a user in a limit order tranche.
+pub struct LimitOrderTrancheUser {
+    pub trade_pair_id: TradePairID,
+    pub tick_index_taker_to_maker: i64,
+    pub tranche_key: String,
+    pub address: String,
+    pub shares_owned: Int128,
+    pub shares_withdrawn: Int128,
+    pub shares_cancelled: Int128,
+    pub order_type: LimitOrderType,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+/// TickLiquidity is liquidity placed at a tick: either pool reserves or a limit order tranche.
+pub enum TickLiquidity {
+    PoolReserves(PoolReserves),
+    LimitOrderTranche(LimitOrderTranche),
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+/// DepositRecord is a liquidity position of a user.
+pub struct DepositRecord {
+    pub pair_id: PairID,
+    pub shares_owned: Int128,
+    pub center_tick_index: i64,
+    pub lower_tick_index: i64,
+    pub upper_tick_index: i64,
+    pub fee: u64,
+    /// **total_shares** and **pool** are only returned if the pool data is requested.
+    pub total_shares: Option<Int128>,
+    pub pool: Option<Pool>,
+}
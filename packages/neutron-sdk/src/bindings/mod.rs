@@ -1,3 +1,7 @@
+pub mod dex;
 pub mod msg;
 pub mod query;
 pub mod types;
+
+#[cfg(test)]
+mod testing;
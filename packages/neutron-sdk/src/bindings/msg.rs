@@ -1,11 +1,25 @@
 use crate::{
+    bindings::dex::msg::DexMsg,
+    bindings::dex::types::{
+        DepositPosition, LimitOrderType, MultiHopRoute, Params as DexParams, PrecDec,
+        WithdrawalPosition,
+    },
+    bindings::query::InterchainQueriesParams,
     bindings::types::{KVKey, ProtobufAny},
-    interchain_queries::types::{QueryPayload, QueryType, TransactionFilterItem, MAX_TX_FILTERS},
+    interchain_queries::types::{
+        validate_transactions_filter, QueryPayload, QueryType, TransactionFilterItem,
+    },
+    query::{
+        feeburner::FeeburnerParams, interchain_txs::InterchainTxsParams,
+        min_ibc_fee::FeeRefunderParams, token_factory::TokenFactoryParams,
+    },
     sudo::msg::RequestPacketTimeoutHeight,
     NeutronError, NeutronResult,
 };
 
-use cosmwasm_std::{Binary, Coin, CosmosMsg, CustomMsg, StdError, Uint128};
+use cosmwasm_std::{
+    Binary, Coin, CosmosMsg, CustomMsg, DenomMetadata, DenomUnit, StdError, Uint128,
+};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use serde_json_wasm::to_string;
@@ -26,6 +40,18 @@ pub struct IbcFee {
     pub timeout_fee: Vec<Coin>,
 }
 
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+/// ChannelOrdering is an ordering of an IBC channel.
+pub enum ChannelOrdering {
+    /// **ORDER_ORDERED** channel delivers packets in the order they were sent,
+    /// a timed out packet closes the channel.
+    OrderOrdered,
+    /// **ORDER_UNORDERED** channel delivers packets in any order,
+    /// a timed out packet doesn't close the channel.
+    OrderUnordered,
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 /// A number of Custom messages that can call into the Neutron bindings.
@@ -38,6 +64,15 @@ pub enum NeutronMsg {
         /// **interchain_account_id** is an identifier of your new interchain account. Can be any string.
         /// This identifier allows contracts to have multiple interchain accounts on remote chains.
         interchain_account_id: String,
+
+        /// **register_fee** is a fee charged for the registration, required by newer Neutron versions.
+        /// Omitted from the message if not set, so the message is accepted by older versions.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        register_fee: Option<Vec<Coin>>,
+
+        /// **ordering** is an ordering of the ICA channel, `ORDER_ORDERED` is used by the module if not set.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        ordering: Option<ChannelOrdering>,
     },
 
     /// SubmitTx starts the process of executing any Cosmos-SDK *msgs* on remote chain.
@@ -148,13 +183,49 @@ pub enum NeutronMsg {
     /// TokenFactory message.
     /// Contracts can burn native tokens for an existing factory denom
     /// that they are the admin of.
-    /// Currently, the burn from address must be the admin contract.
     BurnTokens {
         denom: String,
         amount: Uint128,
-        /// Must be set to `""` for now
+        /// **burn_from_address** is an address to burn the tokens from,
+        /// `""` burns the tokens of the admin contract itself
         burn_from_address: String,
     },
+    /// TokenFactory message.
+    /// Contracts can set a contract which is called before every transfer of a factory denom
+    /// they are the admin of, e.g. to restrict transfers. The hook contract must be whitelisted
+    /// in the module params. Empty `contract_addr` removes the hook.
+    SetBeforeSendHook {
+        denom: String,
+        contract_addr: String,
+    },
+    /// TokenFactory message.
+    /// Contracts can transfer tokens of a factory denom they are the admin of between any accounts.
+    ForceTransfer {
+        denom: String,
+        amount: Uint128,
+        transfer_from_address: String,
+        transfer_to_address: String,
+    },
+    /// TokenFactory message.
+    /// Contracts can set bank metadata of a factory denom they are the admin of.
+    SetDenomMetadata {
+        /// **description** is a description of the token
+        description: String,
+        /// **denom_units** represents the list of units of the denom
+        denom_units: Vec<DenomUnit>,
+        /// **base** is the base denom, must be the factory denom itself
+        base: String,
+        /// **display** is the denom unit used to display the token in clients
+        display: String,
+        /// **name** is a name of the token, e.g. `Atom`
+        name: String,
+        /// **symbol** is a ticker symbol of the token, e.g. `ATOM`
+        symbol: String,
+        /// **uri** is a URI to a document with additional information about the token
+        uri: String,
+        /// **uri_hash** is a sha256 hash of the document referred by **uri**
+        uri_hash: String,
+    },
 
     /// AddSchedule adds new schedule with a given `name`.
     /// Until schedule is removed it will execute all `msgs` every `period` blocks.
@@ -172,6 +243,14 @@ pub enum NeutronMsg {
     /// RemoveSchedule removes the schedule with a given `name`.
     /// [Permissioned - DAO or Security DAO only]
     RemoveSchedule { name: String },
+
+    /// Dex messages to interact with Neutron's DEX (duality) module.
+    Dex(DexMsg),
+
+    /// Contractmanager message.
+    /// Resubmits the failed sudo call with a given `failure_id` to the contract.
+    /// Only the contract the failure belongs to can resubmit it.
+    ResubmitFailure { failure_id: u64 },
 }
 
 impl NeutronMsg {
@@ -185,6 +264,29 @@ impl NeutronMsg {
         NeutronMsg::RegisterInterchainAccount {
             connection_id,
             interchain_account_id,
+            register_fee: None,
+            ordering: None,
+        }
+    }
+
+    /// Helper to define a register interchain account message with additional options:
+    /// * **connection_id** is an IBC connection identifier between Neutron and remote chain;
+    /// * **interchain_account_id** is an identifier of your new interchain account. Can be any string;
+    /// * **register_fee** is a fee charged for the registration, see
+    ///   [`register_interchain_account_with_fee`](crate::query::interchain_txs::register_interchain_account_with_fee)
+    ///   to get it from the module params;
+    /// * **ordering** is an ordering of the ICA channel.
+    pub fn register_interchain_account_with_options(
+        connection_id: String,
+        interchain_account_id: String,
+        register_fee: Option<Vec<Coin>>,
+        ordering: Option<ChannelOrdering>,
+    ) -> Self {
+        NeutronMsg::RegisterInterchainAccount {
+            connection_id,
+            interchain_account_id,
+            register_fee,
+            ordering,
         }
     }
 
@@ -213,6 +315,34 @@ impl NeutronMsg {
         }
     }
 
+    /// Same as [`NeutronMsg::submit_tx`], but fails if there are more **msgs** than
+    /// **max_messages**, which is `msg_submit_tx_max_messages` param of the interchaintxs module
+    /// (see [`query_interchain_txs_params`](crate::query::interchain_txs::query_interchain_txs_params)).
+    pub fn submit_tx_checked(
+        connection_id: String,
+        interchain_account_id: String,
+        msgs: Vec<ProtobufAny>,
+        memo: String,
+        timeout: u64,
+        fee: IbcFee,
+        max_messages: u64,
+    ) -> NeutronResult<Self> {
+        if msgs.len() as u64 > max_messages {
+            return Err(NeutronError::TooManyTxMessages {
+                max: max_messages,
+                actual: msgs.len(),
+            });
+        }
+        Ok(NeutronMsg::submit_tx(
+            connection_id,
+            interchain_account_id,
+            msgs,
+            memo,
+            timeout,
+            fee,
+        ))
+    }
+
     /// Basic helper to define a register interchain query message:
     /// * **query** is a query type identifier ('tx' or 'kv' for now) with a payload:
     ///   - when the query enum is 'kv' then payload is the KV-storage keys for which we want to get
@@ -235,19 +365,14 @@ impl NeutronMsg {
                 update_period,
             },
             QueryPayload::TX(transactions_filters) => {
-                if transactions_filters.len() > MAX_TX_FILTERS {
-                    return Err(NeutronError::TooManyTransactionFilters {
-                        max: MAX_TX_FILTERS,
-                    });
-                } else {
-                    NeutronMsg::RegisterInterchainQuery {
-                        query_type: QueryType::TX.into(),
-                        keys: vec![],
-                        transactions_filter: to_string(&transactions_filters)
-                            .map_err(|e| StdError::generic_err(e.to_string()))?,
-                        connection_id,
-                        update_period,
-                    }
+                validate_transactions_filter(&transactions_filters)?;
+                NeutronMsg::RegisterInterchainQuery {
+                    query_type: QueryType::TX.into(),
+                    keys: vec![],
+                    transactions_filter: to_string(&transactions_filters)
+                        .map_err(|e| StdError::generic_err(e.to_string()))?,
+                    connection_id,
+                    update_period,
                 }
             }
         })
@@ -269,16 +394,8 @@ impl NeutronMsg {
             new_update_period,
             new_transactions_filter: match new_transactions_filter {
                 Some(filters) => {
-                    if filters.len() > MAX_TX_FILTERS {
-                        return Err(NeutronError::TooManyTransactionFilters {
-                            max: MAX_TX_FILTERS,
-                        });
-                    } else {
-                        Some(
-                            to_string(&filters)
-                                .map_err(|e| StdError::generic_err(e.to_string()))?,
-                        )
-                    }
+                    validate_transactions_filter(&filters)?;
+                    Some(to_string(&filters).map_err(|e| StdError::generic_err(e.to_string()))?)
                 }
                 None => None,
             },
@@ -365,6 +482,14 @@ impl NeutronMsg {
         }
     }
 
+    /// Basic helper to define a proposal executing an arbitrary SDK message passed to AdminModule:
+    /// * **proposal** is struct which contains JSON encoded message, see [`ProposalExecuteMessage`] builders.
+    pub fn submit_proposal_execute_message(proposal: ProposalExecuteMessage) -> Self {
+        NeutronMsg::SubmitAdminProposal {
+            admin_proposal: AdminProposal::ProposalExecuteMessage(proposal),
+        }
+    }
+
     pub fn submit_create_denom(subdenom: impl Into<String>) -> Self {
         NeutronMsg::CreateDenom {
             subdenom: subdenom.into(),
@@ -401,6 +526,57 @@ impl NeutronMsg {
         }
     }
 
+    pub fn submit_burn_tokens_from(
+        denom: impl Into<String>,
+        amount: Uint128,
+        burn_from_address: impl Into<String>,
+    ) -> Self {
+        NeutronMsg::BurnTokens {
+            denom: denom.into(),
+            amount,
+            burn_from_address: burn_from_address.into(),
+        }
+    }
+
+    pub fn submit_set_before_send_hook(
+        denom: impl Into<String>,
+        contract_addr: impl Into<String>,
+    ) -> Self {
+        NeutronMsg::SetBeforeSendHook {
+            denom: denom.into(),
+            contract_addr: contract_addr.into(),
+        }
+    }
+
+    pub fn submit_force_transfer(
+        denom: impl Into<String>,
+        amount: Uint128,
+        transfer_from_address: impl Into<String>,
+        transfer_to_address: impl Into<String>,
+    ) -> Self {
+        NeutronMsg::ForceTransfer {
+            denom: denom.into(),
+            amount,
+            transfer_from_address: transfer_from_address.into(),
+            transfer_to_address: transfer_to_address.into(),
+        }
+    }
+
+    /// Basic helper to define a message to set bank metadata of a factory denom:
+    /// * **metadata** is the metadata, its **base** must be the factory denom.
+    pub fn submit_set_denom_metadata(metadata: DenomMetadata) -> Self {
+        NeutronMsg::SetDenomMetadata {
+            description: metadata.description,
+            denom_units: metadata.denom_units,
+            base: metadata.base,
+            display: metadata.display,
+            name: metadata.name,
+            symbol: metadata.symbol,
+            uri: metadata.uri,
+            uri_hash: metadata.uri_hash,
+        }
+    }
+
     pub fn submit_add_schedule(name: String, period: u64, msgs: Vec<MsgExecuteContract>) -> Self {
         NeutronMsg::AddSchedule { name, period, msgs }
     }
@@ -408,6 +584,149 @@ impl NeutronMsg {
     pub fn submit_remove_schedule(name: String) -> Self {
         NeutronMsg::RemoveSchedule { name }
     }
+
+    /// Basic helper to define a message to resubmit a failed sudo call:
+    /// * **failure_id** is an identifier of the failure, see `query_failures`.
+    pub fn resubmit_failure(failure_id: u64) -> Self {
+        NeutronMsg::ResubmitFailure { failure_id }
+    }
+
+    /// Basic helper to define a DEX deposit message:
+    /// * **receiver** is an address which receives pool shares;
+    /// * **token_a** and **token_b** are denoms of the pair;
+    /// * **positions** are liquidity positions to deposit, one per pool.
+    pub fn dex_deposit(
+        receiver: impl Into<String>,
+        token_a: impl Into<String>,
+        token_b: impl Into<String>,
+        positions: Vec<DepositPosition>,
+    ) -> Self {
+        let mut amounts_a = Vec::with_capacity(positions.len());
+        let mut amounts_b = Vec::with_capacity(positions.len());
+        let mut tick_indexes_a_to_b = Vec::with_capacity(positions.len());
+        let mut fees = Vec::with_capacity(positions.len());
+        let mut options = Vec::with_capacity(positions.len());
+        for position in positions {
+            amounts_a.push(position.amount_a);
+            amounts_b.push(position.amount_b);
+            tick_indexes_a_to_b.push(position.tick_index_a_to_b);
+            fees.push(position.fee);
+            options.push(position.options);
+        }
+
+        NeutronMsg::Dex(DexMsg::Deposit {
+            receiver: receiver.into(),
+            token_a: token_a.into(),
+            token_b: token_b.into(),
+            amounts_a,
+            amounts_b,
+            tick_indexes_a_to_b,
+            fees,
+            options,
+        })
+    }
+
+    /// Basic helper to define a DEX withdrawal message:
+    /// * **receiver** is an address which receives the withdrawn tokens;
+    /// * **token_a** and **token_b** are denoms of the pair;
+    /// * **positions** are liquidity positions to withdraw, one per pool.
+    pub fn dex_withdrawal(
+        receiver: impl Into<String>,
+        token_a: impl Into<String>,
+        token_b: impl Into<String>,
+        positions: Vec<WithdrawalPosition>,
+    ) -> Self {
+        let mut shares_to_remove = Vec::with_capacity(positions.len());
+        let mut tick_indexes_a_to_b = Vec::with_capacity(positions.len());
+        let mut fees = Vec::with_capacity(positions.len());
+        for position in positions {
+            shares_to_remove.push(position.shares);
+            tick_indexes_a_to_b.push(position.tick_index_a_to_b);
+            fees.push(position.fee);
+        }
+
+        NeutronMsg::Dex(DexMsg::Withdrawal {
+            receiver: receiver.into(),
+            token_a: token_a.into(),
+            token_b: token_b.into(),
+            shares_to_remove,
+            tick_indexes_a_to_b,
+            fees,
+        })
+    }
+
+    /// Basic helper to define a DEX place limit order message:
+    /// * **receiver** is an address which receives the swapped tokens;
+    /// * **token_in** and **token_out** are denoms of the sold and the bought tokens;
+    /// * **tick_index_in_to_out** is a limit price tick in **token_in** to **token_out** direction;
+    /// * **amount_in** is an amount of **token_in** to sell;
+    /// * **order_type** defines how the order behaves when it can't be filled immediately;
+    /// * **expiration_time** is a unix timestamp in seconds, must be set for `GOOD_TIL_TIME` orders only.
+    pub fn dex_place_limit_order(
+        receiver: impl Into<String>,
+        token_in: impl Into<String>,
+        token_out: impl Into<String>,
+        tick_index_in_to_out: i64,
+        amount_in: Uint128,
+        order_type: LimitOrderType,
+        expiration_time: Option<u64>,
+    ) -> NeutronResult<Self> {
+        if (order_type == LimitOrderType::GoodTilTime) != expiration_time.is_some() {
+            return Err(StdError::generic_err(
+                "expiration_time must be set for GOOD_TIL_TIME limit orders only",
+            )
+            .into());
+        }
+
+        Ok(NeutronMsg::Dex(DexMsg::PlaceLimitOrder {
+            receiver: receiver.into(),
+            token_in: token_in.into(),
+            token_out: token_out.into(),
+            tick_index_in_to_out,
+            amount_in,
+            order_type,
+            expiration_time,
+            max_amount_out: None,
+        }))
+    }
+
+    /// Basic helper to define a DEX message to withdraw the filled part of a limit order:
+    /// * **tranche_key** is a key of the tranche the order belongs to.
+    pub fn dex_withdraw_filled_limit_order(tranche_key: impl Into<String>) -> Self {
+        NeutronMsg::Dex(DexMsg::WithdrawFilledLimitOrder {
+            tranche_key: tranche_key.into(),
+        })
+    }
+
+    /// Basic helper to define a DEX message to cancel a limit order:
+    /// * **tranche_key** is a key of the tranche the order belongs to.
+    pub fn dex_cancel_limit_order(tranche_key: impl Into<String>) -> Self {
+        NeutronMsg::Dex(DexMsg::CancelLimitOrder {
+            tranche_key: tranche_key.into(),
+        })
+    }
+
+    /// Basic helper to define a DEX multi hop swap message:
+    /// * **receiver** is an address which receives the swapped tokens;
+    /// * **routes** are routes to swap through;
+    /// * **amount_in** is an amount of the first denom of the routes to sell;
+    /// * **exit_limit_price** is a minimum price of the whole swap;
+    /// * **pick_best_route** makes the module pick the best route instead of the first suitable one.
+    pub fn dex_multi_hop_swap(
+        receiver: impl Into<String>,
+        routes: Vec<MultiHopRoute>,
+        amount_in: Uint128,
+        exit_limit_price: PrecDec,
+        pick_best_route: bool,
+    ) -> Self {
+        NeutronMsg::Dex(DexMsg::MultiHopSwap {
+            receiver: receiver.into(),
+            routes,
+            amount_in,
+            exit_limit_price,
+            pick_best_route,
+        })
+    }
 }
 
 impl From<NeutronMsg> for CosmosMsg<NeutronMsg> {
@@ -460,6 +779,7 @@ pub enum AdminProposal {
     SudoContractProposal(SudoContractProposal),
     UpdateAdminProposal(UpdateAdminProposal),
     ClearAdminProposal(ClearAdminProposal),
+    ProposalExecuteMessage(ProposalExecuteMessage),
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
@@ -612,6 +932,203 @@ pub struct ClearAdminProposal {
     pub contract: String,
 }
 
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+/// ProposalExecuteMessage defines the struct for a proposal executing an arbitrary SDK message
+/// by newer versions of the Admin Module.
+pub struct ProposalExecuteMessage {
+    /// **message** is a JSON encoded SDK message with its type in the `@type` field, e.g.
+    /// `{"@type":"/cosmos.upgrade.v1beta1.MsgSoftwareUpgrade","authority":"neutron1...","plan":{...}}`.
+    pub message: String,
+}
+
+impl ProposalExecuteMessage {
+    /// Wraps SDK message **msg** of **type_url** type, **msg** must be serialized into a JSON object.
+    pub fn new(type_url: &str, msg: &impl Serialize) -> NeutronResult<Self> {
+        let fields = match serde_json::to_value(msg)? {
+            serde_json::Value::Object(fields) => fields,
+            _ => {
+                return Err(NeutronError::InvalidProposalMessage {
+                    type_url: type_url.to_string(),
+                })
+            }
+        };
+        let mut message = serde_json::Map::new();
+        message.insert("@type".to_string(), type_url.into());
+        message.extend(fields);
+        Ok(ProposalExecuteMessage {
+            message: to_string(&message)?,
+        })
+    }
+
+    /// Builds MsgUpdateParams of the module **params** belong to.
+    /// * **authority** is an address of the module authority, usually the Main DAO;
+    /// * **params** are new parameters of the module, all of them must be set.
+    pub fn update_params<P: ModuleParams>(
+        authority: impl Into<String>,
+        params: P,
+    ) -> NeutronResult<Self> {
+        ProposalExecuteMessage::new(
+            P::MSG_UPDATE_PARAMS_TYPE_URL,
+            &MsgUpdateParams {
+                authority: authority.into(),
+                params,
+            },
+        )
+    }
+
+    /// Builds MsgSoftwareUpgrade.
+    /// * **authority** is an address of the upgrade module authority;
+    /// * **plan** is a plan of the upgrade.
+    pub fn software_upgrade(authority: impl Into<String>, plan: Plan) -> NeutronResult<Self> {
+        ProposalExecuteMessage::new(
+            MSG_SOFTWARE_UPGRADE_TYPE_URL,
+            &MsgSoftwareUpgrade {
+                authority: authority.into(),
+                plan,
+            },
+        )
+    }
+
+    /// Builds MsgStoreCode.
+    /// * **sender** is an address of the wasm module authority;
+    /// * **wasm_byte_code** is a contract code, raw or gzip compressed;
+    /// * **instantiate_permission** restricts who can instantiate the code, the module default is used if not set.
+    pub fn store_code(
+        sender: impl Into<String>,
+        wasm_byte_code: Binary,
+        instantiate_permission: Option<AccessConfig>,
+    ) -> NeutronResult<Self> {
+        ProposalExecuteMessage::new(
+            MSG_STORE_CODE_TYPE_URL,
+            &MsgStoreCode {
+                sender: sender.into(),
+                wasm_byte_code,
+                instantiate_permission,
+            },
+        )
+    }
+}
+
+/// Type URL of the upgrade module MsgSoftwareUpgrade
+pub const MSG_SOFTWARE_UPGRADE_TYPE_URL: &str = "/cosmos.upgrade.v1beta1.MsgSoftwareUpgrade";
+/// Type URL of the wasm module MsgStoreCode
+pub const MSG_STORE_CODE_TYPE_URL: &str = "/cosmwasm.wasm.v1.MsgStoreCode";
+
+/// ModuleParams is implemented for parameters of Neutron modules which can be updated
+/// with [`ProposalExecuteMessage::update_params`].
+pub trait ModuleParams: Serialize {
+    /// **MSG_UPDATE_PARAMS_TYPE_URL** is a type URL of MsgUpdateParams of the module
+    const MSG_UPDATE_PARAMS_TYPE_URL: &'static str;
+}
+
+impl ModuleParams for InterchainQueriesParams {
+    const MSG_UPDATE_PARAMS_TYPE_URL: &'static str = "/neutron.interchainqueries.MsgUpdateParams";
+}
+
+impl ModuleParams for InterchainTxsParams {
+    const MSG_UPDATE_PARAMS_TYPE_URL: &'static str = "/neutron.interchaintxs.v1.MsgUpdateParams";
+}
+
+impl ModuleParams for FeeRefunderParams {
+    const MSG_UPDATE_PARAMS_TYPE_URL: &'static str = "/neutron.feerefunder.MsgUpdateParams";
+}
+
+impl ModuleParams for FeeburnerParams {
+    const MSG_UPDATE_PARAMS_TYPE_URL: &'static str = "/neutron.feeburner.MsgUpdateParams";
+}
+
+impl ModuleParams for TokenFactoryParams {
+    const MSG_UPDATE_PARAMS_TYPE_URL: &'static str =
+        "/osmosis.tokenfactory.v1beta1.MsgUpdateParams";
+}
+
+impl ModuleParams for DexParams {
+    const MSG_UPDATE_PARAMS_TYPE_URL: &'static str = "/neutron.dex.MsgUpdateParams";
+}
+
+impl ModuleParams for CronParams {
+    const MSG_UPDATE_PARAMS_TYPE_URL: &'static str = "/neutron.cron.MsgUpdateParams";
+}
+
+impl ModuleParams for ContractManagerParams {
+    const MSG_UPDATE_PARAMS_TYPE_URL: &'static str = "/neutron.contractmanager.MsgUpdateParams";
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+/// CronParams defines the parameters of the cron module.
+pub struct CronParams {
+    /// **security_address** is an address allowed to add and remove schedules
+    pub security_address: String,
+    /// **limit** is a maximum number of schedules executed per block
+    pub limit: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+/// ContractManagerParams defines the parameters of the contractmanager module.
+pub struct ContractManagerParams {
+    /// **sudo_call_gas_limit** is a gas limit of sudo calls to contracts
+    pub sudo_call_gas_limit: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+/// MsgUpdateParams defines the struct for a module parameters update.
+pub struct MsgUpdateParams<P> {
+    /// **authority** is an address of the module authority.
+    pub authority: String,
+    /// **params** are new parameters of the module.
+    pub params: P,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+/// MsgSoftwareUpgrade defines the struct for a software upgrade.
+pub struct MsgSoftwareUpgrade {
+    /// **authority** is an address of the upgrade module authority.
+    pub authority: String,
+    /// **plan** is a plan of the upgrade.
+    pub plan: Plan,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+/// MsgStoreCode defines the struct for a contract code upload.
+pub struct MsgStoreCode {
+    /// **sender** is an address of the wasm module authority.
+    pub sender: String,
+    /// **wasm_byte_code** is a contract code, raw or gzip compressed.
+    pub wasm_byte_code: Binary,
+    /// **instantiate_permission** restricts who can instantiate the code.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub instantiate_permission: Option<AccessConfig>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+/// AccessConfig defines who can instantiate a contract code.
+pub struct AccessConfig {
+    /// **permission** is a type of the access.
+    pub permission: AccessType,
+    /// **addresses** are addresses allowed to instantiate the code with `ACCESS_TYPE_ANY_OF_ADDRESSES` access.
+    #[serde(default)]
+    pub addresses: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+/// AccessType defines types of a contract code access.
+pub enum AccessType {
+    /// **ACCESS_TYPE_NOBODY** forbids instantiation.
+    AccessTypeNobody,
+    /// **ACCESS_TYPE_EVERYBODY** allows anyone to instantiate.
+    AccessTypeEverybody,
+    /// **ACCESS_TYPE_ANY_OF_ADDRESSES** allows only the listed addresses to instantiate.
+    AccessTypeAnyOfAddresses,
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 /// MsgExecuteContract defines a call to the contract execution
@@ -1,5 +1,6 @@
+use crate::bindings::dex::query::DexQuery;
 use crate::bindings::types::{InterchainQueryResult, RegisteredQuery};
-use cosmwasm_std::{Binary, CustomQuery};
+use cosmwasm_std::{Binary, Coin, CustomQuery};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
@@ -40,12 +41,24 @@ pub enum NeutronQuery {
         query_id: u64,
     },
 
+    /// Query parameters of the interchainqueries module
+    InterchainQueriesParams {},
+
     /// Query total amount of burned neutron fees
     TotalBurnedNeutronsAmount {},
 
+    /// Query parameters of the feeburner module
+    FeeburnerParams {},
+
     /// Query minimum IBC fee
     MinIbcFee {},
 
+    /// Query parameters of the feerefunder module
+    FeeRefunderParams {},
+
+    /// Query parameters of the interchaintxs module
+    InterchainTxsParams {},
+
     /// TokenFactory query. Given a subdenom minted by a contract via
     /// [`NeutronMsg::MintTokens`](crate::bindings::msg::NeutronMsg::MintTokens),
     /// returns the full denom as used by [`BankMsg::Send`](cosmwasm_std::BankMsg::Send).
@@ -56,29 +69,104 @@ pub enum NeutronQuery {
 
     /// TokenFactory query. Returns the admin of a denom, if the denom is a TokenFactory denom.
     DenomAdmin { subdenom: String },
+
+    /// TokenFactory query. Returns all the denoms created by **creator**.
+    DenomsFromCreator { creator: String },
+
+    /// TokenFactory query. Returns the before send hook contract of a denom, if any.
+    BeforeSendHook { denom: String },
+
+    /// TokenFactory query. Returns the parameters of the module.
+    TokenFactoryParams {},
+
+    /// Dex queries to Neutron's DEX (duality) module.
+    Dex(DexQuery),
+
+    /// Cron query. Returns the schedule with a given `name`.
+    Schedule { name: String },
+
+    /// Cron query. Returns all the schedules of the cron module.
+    Schedules { pagination: PageRequest },
+
+    /// Contractmanager query. Returns failures of sudo calls to the contract **address**.
+    Failures {
+        address: String,
+        pagination: PageRequest,
+    },
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub struct PageRequest {
     /// **key** is a value returned in PageResponse.next_key to begin
     /// querying the next page most efficiently. Only one of offset or key
     /// should be set.
-    key: Binary,
+    pub key: Binary,
     /// **offset** is a numeric offset that can be used when key is unavailable.
     /// It is less efficient than using key. Only one of offset or key should
     /// be set.
-    offset: u64,
+    pub offset: u64,
     /// **limit** is the total number of results to be returned in the result page.
     /// If left empty it will default to a value to be set by each app.
-    limit: u64,
+    pub limit: u64,
     /// **count_total** is set to true  to indicate that the result set should include
     /// a count of the total number of items available for pagination in UIs.
     /// count_total is only respected when offset is used. It is ignored when key
     /// is set.
-    count_total: bool,
+    pub count_total: bool,
     /// reverse is set to true if results are to be returned in the descending order.
-    reverse: bool,
+    pub reverse: bool,
+}
+
+impl PageRequest {
+    /// Creates a request for the first page with default limit
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets **key** to begin querying the page from
+    pub fn with_key(mut self, key: Binary) -> Self {
+        self.key = key;
+        self
+    }
+
+    /// Sets numeric **offset** to begin querying the page from
+    pub fn with_offset(mut self, offset: u64) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Sets maximum number of results in the page
+    pub fn with_limit(mut self, limit: u64) -> Self {
+        self.limit = limit;
+        self
+    }
+
+    /// Sets whether the total number of items should be counted
+    pub fn with_count_total(mut self, count_total: bool) -> Self {
+        self.count_total = count_total;
+        self
+    }
+
+    /// Sets whether the results should be returned in the descending order
+    pub fn with_reverse(mut self, reverse: bool) -> Self {
+        self.reverse = reverse;
+        self
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct PageResponse {
+    /// **next_key** is the key to be passed to PageRequest.key to
+    /// query the next page most efficiently. It will be empty if
+    /// there are no more results.
+    #[serde(default)]
+    pub next_key: Option<Binary>,
+    /// **total** is total number of results available if PageRequest.count_total
+    /// was set, its value is undefined otherwise
+    #[serde(default)]
+    pub total: u64,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
@@ -86,6 +174,10 @@ pub struct PageRequest {
 pub struct QueryRegisteredQueriesResponse {
     /// **registered_queries** is a list of registered queries
     pub registered_queries: Vec<RegisteredQuery>,
+    /// **pagination** is a pagination info of the response.
+    /// Older Neutron versions don't return it.
+    #[serde(default)]
+    pub pagination: Option<PageResponse>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
@@ -101,6 +193,28 @@ pub struct QueryRegisteredQueryResultResponse {
     pub result: InterchainQueryResult,
 }
 
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct InterchainQueriesParams {
+    /// **query_submit_timeout** is a number of blocks after which a query is considered timed out
+    /// if no results were submitted, and anyone can remove it and get its deposit
+    #[serde(default)]
+    pub query_submit_timeout: u64,
+    /// **query_deposit** is an amount of coins locked for every registered query
+    #[serde(default)]
+    pub query_deposit: Vec<Coin>,
+    /// **tx_query_removal_limit** is a maximum number of tx query hashes removed from the storage
+    /// per block when a tx query is removed
+    #[serde(default)]
+    pub tx_query_removal_limit: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct QueryInterchainQueriesParamsResponse {
+    pub params: InterchainQueriesParams,
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub struct QueryInterchainAccountAddressResponse {
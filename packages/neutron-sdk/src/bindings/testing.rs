@@ -0,0 +1,730 @@
+use crate::bindings::dex::msg::DexMsg;
+use crate::bindings::dex::query::{AllTickLiquidityResponse, DexQuery};
+use crate::bindings::dex::types::{
+    DepositOption, DepositPosition, LimitOrderType, MultiHopRoute, PrecDec, TickLiquidity,
+    WithdrawalPosition,
+};
+use crate::bindings::msg::{
+    AccessConfig, AccessType, AdminProposal, ChannelOrdering, ContractManagerParams, CronParams,
+    IbcFee, ModuleParams, NeutronMsg, Plan, ProposalExecuteMessage,
+};
+use crate::bindings::query::{NeutronQuery, PageRequest};
+use crate::bindings::types::{decode_hex, encode_hex, KVKey, KVKeyParseError, KVKeys, ProtobufAny};
+use crate::query::dex::{estimate_query, pair_id};
+use crate::query::interchain_txs::InterchainTxsParams;
+use crate::NeutronError;
+use cosmwasm_std::{coin, from_slice, to_vec, Binary, Int128, Uint128};
+
+fn kv_key(path: &str, key: &[u8]) -> KVKey {
+    KVKey {
+        path: path.to_string(),
+        key: Binary::from(key),
+    }
+}
+
+#[test]
+fn test_decode_hex() {
+    struct TestCase {
+        input: &'static str,
+        expected: Option<Vec<u8>>,
+    }
+    let test_cases = vec![
+        TestCase {
+            input: "",
+            expected: Some(vec![]),
+        },
+        TestCase {
+            input: "00ff10",
+            expected: Some(vec![0x00, 0xff, 0x10]),
+        },
+        TestCase {
+            input: "ABcd",
+            expected: Some(vec![0xab, 0xcd]),
+        },
+        TestCase {
+            input: "abc",
+            expected: None,
+        },
+        TestCase {
+            input: "zz",
+            expected: None,
+        },
+        TestCase {
+            input: "+1",
+            expected: None,
+        },
+        TestCase {
+            input: "é",
+            expected: None,
+        },
+    ];
+
+    for tc in test_cases {
+        assert_eq!(decode_hex(tc.input), tc.expected, "input {:?}", tc.input);
+    }
+
+    let bytes: Vec<u8> = (0..=255).collect();
+    assert_eq!(decode_hex(&encode_hex(&bytes)), Some(bytes));
+}
+
+#[test]
+fn test_kv_key_parse() {
+    struct TestCase {
+        input: &'static str,
+        expected: Result<KVKey, KVKeyParseError>,
+    }
+    let test_cases = vec![
+        TestCase {
+            input: "bank/0102ff",
+            expected: Ok(kv_key("bank", &[0x01, 0x02, 0xff])),
+        },
+        TestCase {
+            input: "bank/0102FF",
+            expected: Ok(kv_key("bank", &[0x01, 0x02, 0xff])),
+        },
+        TestCase {
+            input: "bank",
+            expected: Err(KVKeyParseError::MissingDelimiter("bank".to_string())),
+        },
+        TestCase {
+            input: "bank/01/02",
+            expected: Err(KVKeyParseError::TooManySegments("bank/01/02".to_string())),
+        },
+        TestCase {
+            input: "/01",
+            expected: Err(KVKeyParseError::EmptyPath("/01".to_string())),
+        },
+        TestCase {
+            input: "bank/",
+            expected: Ok(kv_key("bank", &[])),
+        },
+        TestCase {
+            input: "bank/010",
+            expected: Err(KVKeyParseError::InvalidHex("bank/010".to_string())),
+        },
+        TestCase {
+            input: "bank/0g",
+            expected: Err(KVKeyParseError::InvalidHex("bank/0g".to_string())),
+        },
+    ];
+
+    for tc in test_cases {
+        assert_eq!(
+            tc.input.parse::<KVKey>(),
+            tc.expected,
+            "input {:?}",
+            tc.input
+        );
+        assert_eq!(KVKey::from_string(tc.input), tc.expected.ok());
+    }
+}
+
+#[test]
+fn test_kv_keys_parse() {
+    assert_eq!("".parse::<KVKeys>(), Ok(KVKeys(vec![])));
+    assert_eq!(
+        "bank/01,staking/0203".parse::<KVKeys>(),
+        Ok(KVKeys(vec![
+            kv_key("bank", &[0x01]),
+            kv_key("staking", &[0x02, 0x03])
+        ]))
+    );
+    assert_eq!(
+        "bank/01,".parse::<KVKeys>(),
+        Err(KVKeyParseError::MissingDelimiter("".to_string()))
+    );
+    assert_eq!(
+        "bank/01,staking/020".parse::<KVKeys>(),
+        Err(KVKeyParseError::InvalidHex("staking/020".to_string()))
+    );
+    assert_eq!(KVKeys::from_string("bank/01,staking"), None);
+
+    let err: NeutronError = "bank".parse::<KVKey>().unwrap_err().into();
+    assert_eq!(
+        err.to_string(),
+        "Invalid KV key: KV key \"bank\" has no path delimiter"
+    );
+}
+
+#[test]
+fn test_kv_keys_round_trip() {
+    let keys = KVKeys(vec![
+        kv_key("bank", &[0x02, 0x14, 0xab]),
+        kv_key("staking", &[0x00]),
+        kv_key("wasm", &(0..=255).collect::<Vec<u8>>()),
+    ]);
+
+    let s = keys.to_string();
+    assert!(s.starts_with("bank/0214ab,staking/00,wasm/000102"));
+    assert_eq!(s.parse::<KVKeys>(), Ok(keys.clone()));
+
+    let into: String = keys.clone().into();
+    assert_eq!(into, s);
+    for key in &keys.0 {
+        let into: String = key.into();
+        assert_eq!(into.parse::<KVKey>().as_ref(), Ok(key));
+    }
+
+    assert_eq!(KVKeys::default().to_string(), "");
+    assert_eq!("".parse::<KVKeys>(), Ok(KVKeys::default()));
+
+    // an empty key survives the round trip
+    let keys = KVKeys(vec![kv_key("bank", &[]), kv_key("staking", &[0x01])]);
+    let json = to_vec(&keys).unwrap();
+    assert_eq!(json, br#""bank/,staking/01""#.to_vec());
+    assert_eq!(from_slice::<KVKeys>(&json).unwrap(), keys);
+
+    // keys which can't be parsed back are not serialized
+    for (key, expected) in [
+        (
+            kv_key("", &[0x01]),
+            KVKeyParseError::EmptyPath("/01".to_string()),
+        ),
+        (
+            kv_key("wasm/contract", &[0x01]),
+            KVKeyParseError::InvalidPath("wasm/contract".to_string()),
+        ),
+        (
+            kv_key("bank,staking", &[0x01]),
+            KVKeyParseError::InvalidPath("bank,staking".to_string()),
+        ),
+    ] {
+        assert_eq!(key.validate(), Err(expected));
+        assert!(to_vec(&KVKeys(vec![kv_key("bank", &[0x01]), key])).is_err());
+    }
+    assert_eq!(kv_key("bank", &[]).validate(), Ok(()));
+}
+
+#[test]
+fn test_kv_keys_serde() {
+    let keys = KVKeys(vec![kv_key("bank", &[0x01]), kv_key("staking", &[0x02])]);
+
+    let json = to_vec(&keys).unwrap();
+    assert_eq!(json, br#""bank/01,staking/02""#.to_vec());
+    assert_eq!(from_slice::<KVKeys>(&json).unwrap(), keys);
+
+    assert!(from_slice::<KVKeys>(br#""bank/01,staking""#).is_err());
+    assert!(from_slice::<KVKeys>(br#"["bank/01"]"#).is_err());
+
+    // KVKey keeps its struct form in messages
+    assert_eq!(
+        to_vec(&kv_key("bank", &[0x01])).unwrap(),
+        br#"{"path":"bank","key":"AQ=="}"#.to_vec()
+    );
+}
+
+#[test]
+fn test_dex_deposit() {
+    let msg = NeutronMsg::dex_deposit(
+        "receiver",
+        "untrn",
+        "uatom",
+        vec![
+            DepositPosition {
+                amount_a: Uint128::new(100),
+                amount_b: Uint128::new(200),
+                tick_index_a_to_b: -10,
+                fee: 1,
+                options: DepositOption::default(),
+            },
+            DepositPosition {
+                amount_a: Uint128::new(300),
+                amount_b: Uint128::zero(),
+                tick_index_a_to_b: 20,
+                fee: 5,
+                options: DepositOption {
+                    disable_autoswap: true,
+                },
+            },
+        ],
+    );
+
+    assert_eq!(
+        to_vec(&msg).unwrap(),
+        br#"{"dex":{"deposit":{"receiver":"receiver","token_a":"untrn","token_b":"uatom","amounts_a":["100","300"],"amounts_b":["200","0"],"tick_indexes_a_to_b":[-10,20],"fees":[1,5],"options":[{"disable_autoswap":false},{"disable_autoswap":true}]}}}"#.to_vec()
+    );
+
+    assert_eq!(
+        NeutronMsg::dex_withdrawal(
+            "receiver",
+            "untrn",
+            "uatom",
+            vec![WithdrawalPosition {
+                shares: Uint128::new(10),
+                tick_index_a_to_b: -10,
+                fee: 1,
+            }],
+        ),
+        NeutronMsg::Dex(DexMsg::Withdrawal {
+            receiver: "receiver".to_string(),
+            token_a: "untrn".to_string(),
+            token_b: "uatom".to_string(),
+            shares_to_remove: vec![Uint128::new(10)],
+            tick_indexes_a_to_b: vec![-10],
+            fees: vec![1],
+        })
+    );
+}
+
+#[test]
+fn test_dex_place_limit_order() {
+    struct TestCase {
+        order_type: LimitOrderType,
+        expiration_time: Option<u64>,
+        valid: bool,
+    }
+    let test_cases = vec![
+        TestCase {
+            order_type: LimitOrderType::GoodTilCancelled,
+            expiration_time: None,
+            valid: true,
+        },
+        TestCase {
+            order_type: LimitOrderType::FillOrKill,
+            expiration_time: None,
+            valid: true,
+        },
+        TestCase {
+            order_type: LimitOrderType::ImmediateOrCancel,
+            expiration_time: Some(1_700_000_000),
+            valid: false,
+        },
+        TestCase {
+            order_type: LimitOrderType::GoodTilTime,
+            expiration_time: Some(1_700_000_000),
+            valid: true,
+        },
+        TestCase {
+            order_type: LimitOrderType::GoodTilTime,
+            expiration_time: None,
+            valid: false,
+        },
+    ];
+
+    for tc in test_cases {
+        let result = NeutronMsg::dex_place_limit_order(
+            "receiver",
+            "untrn",
+            "uatom",
+            -5,
+            Uint128::new(1000),
+            tc.order_type,
+            tc.expiration_time,
+        );
+        assert_eq!(result.is_ok(), tc.valid, "order type {:?}", tc.order_type);
+    }
+
+    let msg = NeutronMsg::dex_place_limit_order(
+        "receiver",
+        "untrn",
+        "uatom",
+        -5,
+        Uint128::new(1000),
+        LimitOrderType::GoodTilTime,
+        Some(1_700_000_000),
+    )
+    .unwrap();
+    assert_eq!(
+        to_vec(&msg).unwrap(),
+        br#"{"dex":{"place_limit_order":{"receiver":"receiver","token_in":"untrn","token_out":"uatom","tick_index_in_to_out":-5,"amount_in":"1000","order_type":"GOOD_TIL_TIME","expiration_time":1700000000,"max_amount_out":null}}}"#.to_vec()
+    );
+    assert_eq!(
+        to_vec(&NeutronMsg::dex_cancel_limit_order("TRANCHEKEY")).unwrap(),
+        br#"{"dex":{"cancel_limit_order":{"tranche_key":"TRANCHEKEY"}}}"#.to_vec()
+    );
+}
+
+#[test]
+fn test_dex_estimate_query() {
+    let swap = match NeutronMsg::dex_multi_hop_swap(
+        "receiver",
+        vec![MultiHopRoute {
+            hops: vec![
+                "untrn".to_string(),
+                "uatom".to_string(),
+                "uosmo".to_string(),
+            ],
+        }],
+        Uint128::new(1000),
+        PrecDec("0.9".to_string()),
+        true,
+    ) {
+        NeutronMsg::Dex(msg) => msg,
+        _ => unreachable!(),
+    };
+
+    assert_eq!(
+        estimate_query("creator".to_string(), swap).unwrap(),
+        DexQuery::EstimateMultiHopSwap {
+            creator: "creator".to_string(),
+            receiver: "receiver".to_string(),
+            routes: vec![MultiHopRoute {
+                hops: vec![
+                    "untrn".to_string(),
+                    "uatom".to_string(),
+                    "uosmo".to_string()
+                ],
+            }],
+            amount_in: Uint128::new(1000),
+            exit_limit_price: PrecDec("0.9".to_string()),
+            pick_best_route: true,
+        }
+    );
+    assert!(estimate_query(
+        "creator".to_string(),
+        DexMsg::CancelLimitOrder {
+            tranche_key: "TRANCHEKEY".to_string(),
+        },
+    )
+    .is_err());
+
+    assert_eq!(pair_id("untrn", "uatom"), "uatom<>untrn");
+    assert_eq!(pair_id("uatom", "untrn"), "uatom<>untrn");
+}
+
+#[test]
+fn test_dex_query_serialization() {
+    let page = || Some(PageRequest::new().with_limit(10));
+    let page_json = r#"{"key":"","offset":0,"limit":10,"count_total":false,"reverse":false}"#;
+    let test_cases = vec![
+        (DexQuery::Params {}, r#"{"params":{}}"#.to_string()),
+        (
+            DexQuery::Pool {
+                pair_id: "uatom<>untrn".to_string(),
+                tick_index: -5,
+                fee: 1,
+            },
+            r#"{"pool":{"pair_id":"uatom<>untrn","tick_index":-5,"fee":1}}"#.to_string(),
+        ),
+        (
+            DexQuery::PoolByID { pool_id: 3 },
+            r#"{"pool_by_id":{"pool_id":3}}"#.to_string(),
+        ),
+        (
+            DexQuery::PoolReserves {
+                pair_id: "uatom<>untrn".to_string(),
+                token_in: "untrn".to_string(),
+                tick_index: 2,
+                fee: 1,
+            },
+            r#"{"pool_reserves":{"pair_id":"uatom<>untrn","token_in":"untrn","tick_index":2,"fee":1}}"#
+                .to_string(),
+        ),
+        (
+            DexQuery::PoolReservesAll {
+                pair_id: "uatom<>untrn".to_string(),
+                token_in: "untrn".to_string(),
+                pagination: page(),
+            },
+            format!(
+                r#"{{"pool_reserves_all":{{"pair_id":"uatom<>untrn","token_in":"untrn","pagination":{}}}}}"#,
+                page_json
+            ),
+        ),
+        (
+            DexQuery::TickLiquidityAll {
+                pair_id: "uatom<>untrn".to_string(),
+                token_in: "untrn".to_string(),
+                pagination: None,
+            },
+            r#"{"tick_liquidity_all":{"pair_id":"uatom<>untrn","token_in":"untrn","pagination":null}}"#
+                .to_string(),
+        ),
+        (
+            DexQuery::LimitOrderTranche {
+                pair_id: "uatom<>untrn".to_string(),
+                tick_index: 2,
+                token_in: "untrn".to_string(),
+                tranche_key: "key".to_string(),
+            },
+            r#"{"limit_order_tranche":{"pair_id":"uatom<>untrn","tick_index":2,"token_in":"untrn","tranche_key":"key"}}"#
+                .to_string(),
+        ),
+        (
+            DexQuery::LimitOrderTrancheAll {
+                pair_id: "uatom<>untrn".to_string(),
+                token_in: "untrn".to_string(),
+                pagination: page(),
+            },
+            format!(
+                r#"{{"limit_order_tranche_all":{{"pair_id":"uatom<>untrn","token_in":"untrn","pagination":{}}}}}"#,
+                page_json
+            ),
+        ),
+        (
+            DexQuery::LimitOrderTrancheUser {
+                address: "addr".to_string(),
+                tranche_key: "key".to_string(),
+            },
+            r#"{"limit_order_tranche_user":{"address":"addr","tranche_key":"key"}}"#.to_string(),
+        ),
+        (
+            DexQuery::LimitOrderTrancheUserAllByAddress {
+                address: "addr".to_string(),
+                pagination: page(),
+            },
+            format!(
+                r#"{{"limit_order_tranche_user_all_by_address":{{"address":"addr","pagination":{}}}}}"#,
+                page_json
+            ),
+        ),
+        (
+            DexQuery::UserDepositAll {
+                address: "addr".to_string(),
+                include_pool_data: true,
+                pagination: None,
+            },
+            r#"{"user_deposit_all":{"address":"addr","include_pool_data":true,"pagination":null}}"#
+                .to_string(),
+        ),
+        (
+            DexQuery::EstimateMultiHopSwap {
+                creator: "creator".to_string(),
+                receiver: "receiver".to_string(),
+                routes: vec![MultiHopRoute {
+                    hops: vec!["untrn".to_string(), "uatom".to_string()],
+                }],
+                amount_in: Uint128::new(100),
+                exit_limit_price: PrecDec("0.5".to_string()),
+                pick_best_route: false,
+            },
+            r#"{"estimate_multi_hop_swap":{"creator":"creator","receiver":"receiver","routes":[{"hops":["untrn","uatom"]}],"amount_in":"100","exit_limit_price":"0.5","pick_best_route":false}}"#
+                .to_string(),
+        ),
+        (
+            DexQuery::EstimatePlaceLimitOrder {
+                creator: "creator".to_string(),
+                receiver: "receiver".to_string(),
+                token_in: "untrn".to_string(),
+                token_out: "uatom".to_string(),
+                tick_index_in_to_out: -1,
+                amount_in: Uint128::new(100),
+                order_type: LimitOrderType::GoodTilTime,
+                expiration_time: Some(1700000000),
+                max_amount_out: None,
+            },
+            r#"{"estimate_place_limit_order":{"creator":"creator","receiver":"receiver","token_in":"untrn","token_out":"uatom","tick_index_in_to_out":-1,"amount_in":"100","order_type":"GOOD_TIL_TIME","expiration_time":1700000000,"max_amount_out":null}}"#
+                .to_string(),
+        ),
+    ];
+
+    for (query, expected) in test_cases {
+        assert_eq!(
+            String::from_utf8(to_vec(&query).unwrap()).unwrap(),
+            expected
+        );
+        assert_eq!(from_slice::<DexQuery>(expected.as_bytes()).unwrap(), query);
+    }
+}
+
+#[test]
+fn test_dex_query_responses() {
+    let query = NeutronQuery::Dex(DexQuery::TickLiquidityAll {
+        pair_id: "uatom<>untrn".to_string(),
+        token_in: "untrn".to_string(),
+        pagination: None,
+    });
+    assert_eq!(
+        to_vec(&query).unwrap(),
+        br#"{"dex":{"tick_liquidity_all":{"pair_id":"uatom<>untrn","token_in":"untrn","pagination":null}}}"#.to_vec()
+    );
+
+    let resp: AllTickLiquidityResponse = from_slice(
+        br#"{"tick_liquidity":[{"pool_reserves":{"key":{"trade_pair_id":{"maker_denom":"untrn","taker_denom":"uatom"},"tick_index_taker_to_maker":-1,"fee":1},"reserves_maker_denom":"100","price_taker_to_maker":"1.0001","price_opposite_taker_to_maker":"0.9999"}}]}"#,
+    )
+    .unwrap();
+    assert_eq!(resp.pagination, None);
+    match &resp.tick_liquidity[..] {
+        [TickLiquidity::PoolReserves(reserves)] => {
+            assert_eq!(reserves.key.tick_index_taker_to_maker, -1);
+            assert_eq!(reserves.reserves_maker_denom, Int128::new(100));
+            assert_eq!(reserves.price_taker_to_maker, PrecDec("1.0001".to_string()));
+        }
+        _ => panic!("unexpected tick liquidity: {:?}", resp.tick_liquidity),
+    }
+}
+
+#[test]
+fn test_submit_tx_checked() {
+    let fee = IbcFee {
+        recv_fee: vec![],
+        ack_fee: vec![coin(1000, "untrn")],
+        timeout_fee: vec![coin(1000, "untrn")],
+    };
+    let msgs = vec![
+        ProtobufAny {
+            type_url: "/cosmos.bank.v1beta1.MsgSend".to_string(),
+            value: Binary::default(),
+        };
+        3
+    ];
+    let submit_tx = |max_messages| {
+        NeutronMsg::submit_tx_checked(
+            "connection-0".to_string(),
+            "ica".to_string(),
+            msgs.clone(),
+            String::new(),
+            100,
+            fee.clone(),
+            max_messages,
+        )
+    };
+
+    assert_eq!(
+        submit_tx(3).unwrap(),
+        NeutronMsg::submit_tx(
+            "connection-0".to_string(),
+            "ica".to_string(),
+            msgs.clone(),
+            String::new(),
+            100,
+            fee.clone(),
+        )
+    );
+    assert_eq!(
+        submit_tx(2).unwrap_err(),
+        NeutronError::TooManyTxMessages { max: 2, actual: 3 }
+    );
+}
+
+#[test]
+fn test_register_interchain_account_serialization() {
+    // older Neutron versions don't know about the fee and ordering, so unset options are omitted
+    assert_eq!(
+        to_vec(&NeutronMsg::register_interchain_account(
+            "connection-0".to_string(),
+            "ica".to_string()
+        ))
+        .unwrap(),
+        br#"{"register_interchain_account":{"connection_id":"connection-0","interchain_account_id":"ica"}}"#
+            .to_vec()
+    );
+    let msg = NeutronMsg::register_interchain_account_with_options(
+        "connection-0".to_string(),
+        "ica".to_string(),
+        Some(vec![coin(1000, "untrn")]),
+        Some(ChannelOrdering::OrderUnordered),
+    );
+    let serialized = br#"{"register_interchain_account":{"connection_id":"connection-0","interchain_account_id":"ica","register_fee":[{"denom":"untrn","amount":"1000"}],"ordering":"ORDER_UNORDERED"}}"#;
+    assert_eq!(to_vec(&msg).unwrap(), serialized.to_vec());
+    assert_eq!(from_slice::<NeutronMsg>(serialized).unwrap(), msg);
+
+    assert_eq!(
+        from_slice::<NeutronMsg>(
+            br#"{"register_interchain_account":{"connection_id":"connection-0","interchain_account_id":"ica"}}"#
+        )
+        .unwrap(),
+        NeutronMsg::register_interchain_account("connection-0".to_string(), "ica".to_string())
+    );
+}
+
+#[test]
+fn test_proposal_execute_message() {
+    struct TestCase {
+        proposal: ProposalExecuteMessage,
+        expected_message: &'static str,
+    }
+    let test_cases = vec![
+        TestCase {
+            proposal: ProposalExecuteMessage::update_params(
+                "neutron1dao",
+                InterchainTxsParams {
+                    msg_submit_tx_max_messages: 16,
+                    register_fee: vec![coin(1000, "untrn")],
+                },
+            )
+            .unwrap(),
+            expected_message: r#"{"@type":"/neutron.interchaintxs.v1.MsgUpdateParams","authority":"neutron1dao","params":{"msg_submit_tx_max_messages":16,"register_fee":[{"amount":"1000","denom":"untrn"}]}}"#,
+        },
+        TestCase {
+            proposal: ProposalExecuteMessage::update_params(
+                "neutron1dao",
+                CronParams {
+                    security_address: "neutron1security".to_string(),
+                    limit: 5,
+                },
+            )
+            .unwrap(),
+            expected_message: r#"{"@type":"/neutron.cron.MsgUpdateParams","authority":"neutron1dao","params":{"limit":5,"security_address":"neutron1security"}}"#,
+        },
+        TestCase {
+            proposal: ProposalExecuteMessage::software_upgrade(
+                "neutron1dao",
+                Plan {
+                    name: "v2.0.0".to_string(),
+                    height: 100,
+                    info: String::new(),
+                },
+            )
+            .unwrap(),
+            expected_message: r#"{"@type":"/cosmos.upgrade.v1beta1.MsgSoftwareUpgrade","authority":"neutron1dao","plan":{"height":100,"info":"","name":"v2.0.0"}}"#,
+        },
+        TestCase {
+            proposal: ProposalExecuteMessage::store_code(
+                "neutron1dao",
+                Binary::from(b"wasm".as_slice()),
+                None,
+            )
+            .unwrap(),
+            expected_message: r#"{"@type":"/cosmwasm.wasm.v1.MsgStoreCode","sender":"neutron1dao","wasm_byte_code":"d2FzbQ=="}"#,
+        },
+        TestCase {
+            proposal: ProposalExecuteMessage::store_code(
+                "neutron1dao",
+                Binary::from(b"wasm".as_slice()),
+                Some(AccessConfig {
+                    permission: AccessType::AccessTypeAnyOfAddresses,
+                    addresses: vec!["neutron1dao".to_string()],
+                }),
+            )
+            .unwrap(),
+            expected_message: r#"{"@type":"/cosmwasm.wasm.v1.MsgStoreCode","instantiate_permission":{"addresses":["neutron1dao"],"permission":"ACCESS_TYPE_ANY_OF_ADDRESSES"},"sender":"neutron1dao","wasm_byte_code":"d2FzbQ=="}"#,
+        },
+        TestCase {
+            proposal: ProposalExecuteMessage::new(
+                ContractManagerParams::MSG_UPDATE_PARAMS_TYPE_URL,
+                &ContractManagerParams {
+                    sudo_call_gas_limit: 1_000_000,
+                },
+            )
+            .unwrap(),
+            expected_message: r#"{"@type":"/neutron.contractmanager.MsgUpdateParams","sudo_call_gas_limit":1000000}"#,
+        },
+        TestCase {
+            proposal: ProposalExecuteMessage::new("/cosmos.gov.v1.MsgEmpty", &MsgEmpty {}).unwrap(),
+            expected_message: r#"{"@type":"/cosmos.gov.v1.MsgEmpty"}"#,
+        },
+    ];
+
+    for tc in test_cases {
+        assert_eq!(tc.proposal.message, tc.expected_message);
+    }
+
+    assert_eq!(
+        ProposalExecuteMessage::new("/cosmos.gov.v1.MsgEmpty", &"not an object"),
+        Err(NeutronError::InvalidProposalMessage {
+            type_url: "/cosmos.gov.v1.MsgEmpty".to_string(),
+        })
+    );
+
+    let proposal = ProposalExecuteMessage {
+        message: r#"{"@type":"/cosmos.gov.v1.MsgEmpty"}"#.to_string(),
+    };
+    assert_eq!(
+        NeutronMsg::submit_proposal_execute_message(proposal.clone()),
+        NeutronMsg::SubmitAdminProposal {
+            admin_proposal: AdminProposal::ProposalExecuteMessage(proposal),
+        }
+    );
+    assert_eq!(
+        to_vec(&NeutronMsg::submit_proposal_execute_message(ProposalExecuteMessage {
+            message: "{}".to_string(),
+        }))
+        .unwrap(),
+        br#"{"submit_admin_proposal":{"admin_proposal":{"proposal_execute_message":{"message":"{}"}}}}"#
+            .to_vec()
+    );
+}
+
+#[derive(serde::Serialize)]
+struct MsgEmpty {}
@@ -1,7 +1,11 @@
 use cosmwasm_std::{Binary, Coin};
+use schemars::gen::SchemaGenerator;
+use schemars::schema::Schema;
 use schemars::JsonSchema;
-use serde::{Deserialize, Serialize};
-use std::fmt::Write as _;
+use serde::{de, ser, Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt::{self, Write as _};
+use std::str::FromStr;
+use thiserror::Error;
 
 use crate::interchain_queries::types::QueryType;
 
@@ -14,14 +18,24 @@ pub fn encode_hex(bytes: &[u8]) -> String {
     s
 }
 
-/// Decodes hex string into bytes vec
+/// Decodes hex string (in lower or upper case) into bytes vec
+/// Returns None if the string has odd length or contains non-hex characters
 pub fn decode_hex(s: &str) -> Option<Vec<u8>> {
-    (0..s.len())
-        .step_by(2)
-        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).ok())
+    let bytes = s.as_bytes();
+    if bytes.len() % 2 != 0 {
+        return None;
+    }
+
+    bytes
+        .chunks_exact(2)
+        .map(|pair| Some(hex_digit(pair[0])? << 4 | hex_digit(pair[1])?))
         .collect()
 }
 
+fn hex_digit(c: u8) -> Option<u8> {
+    (c as char).to_digit(16).map(|d| d as u8)
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub struct RegisteredQuery {
@@ -119,6 +133,25 @@ impl ProtobufAny {
 const KV_PATH_KEY_DELIMITER: &str = "/";
 const KV_KEYS_DELIMITER: &str = ",";
 
+/// Error of parsing [KVKey] or [KVKeys] from the `path/hexkey[,path/hexkey...]` string form
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum KVKeyParseError {
+    #[error("KV key {0:?} has no path delimiter")]
+    MissingDelimiter(String),
+
+    #[error("KV key {0:?} has more than one path delimiter")]
+    TooManySegments(String),
+
+    #[error("KV key {0:?} has empty path")]
+    EmptyPath(String),
+
+    #[error("KV key path {0:?} contains a delimiter")]
+    InvalidPath(String),
+
+    #[error("KV key {0:?} has invalid hex key")]
+    InvalidHex(String),
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 /// Describes a KV key for which you want to get value from the storage on remote chain
@@ -132,59 +165,138 @@ pub struct KVKey {
 
 impl KVKey {
     /// Creates KVKey from string
-    /// Returns None on failure
+    /// Returns None on failure, use [str::parse] to get the reason
     pub fn from_string<S: AsRef<str>>(s: S) -> Option<KVKey> {
-        let split: Vec<&str> = s.as_ref().split(KV_PATH_KEY_DELIMITER).collect();
-        if split.len() < 2 {
-            return None;
+        s.as_ref().parse().ok()
+    }
+
+    /// Checks that the key can be written in the `path/hexkey` string form and parsed back,
+    /// i.e. its path is not empty and contains neither `/` nor `,`
+    pub fn validate(&self) -> Result<(), KVKeyParseError> {
+        if self.path.is_empty() {
+            return Err(KVKeyParseError::EmptyPath(self.to_string()));
         }
+        if self.path.contains(KV_PATH_KEY_DELIMITER) || self.path.contains(KV_KEYS_DELIMITER) {
+            return Err(KVKeyParseError::InvalidPath(self.path.clone()));
+        }
+        Ok(())
+    }
+}
 
-        Some(KVKey {
-            path: split[0].to_string(),
-            key: Binary(decode_hex(split[1])?),
+impl FromStr for KVKey {
+    type Err = KVKeyParseError;
+
+    /// Parses KVKey from `path/hexkey` string, e.g. `bank/0214...`. The hex key may be empty.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (path, key) = s
+            .split_once(KV_PATH_KEY_DELIMITER)
+            .ok_or_else(|| KVKeyParseError::MissingDelimiter(s.to_string()))?;
+        if key.contains(KV_PATH_KEY_DELIMITER) {
+            return Err(KVKeyParseError::TooManySegments(s.to_string()));
+        }
+        if path.is_empty() {
+            return Err(KVKeyParseError::EmptyPath(s.to_string()));
+        }
+
+        Ok(KVKey {
+            path: path.to_string(),
+            key: Binary(decode_hex(key).ok_or_else(|| KVKeyParseError::InvalidHex(s.to_string()))?),
         })
     }
 }
 
+impl fmt::Display for KVKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}{}{}",
+            self.path,
+            KV_PATH_KEY_DELIMITER,
+            encode_hex(&self.key)
+        )
+    }
+}
+
 #[allow(clippy::from_over_into)]
 impl Into<String> for &KVKey {
     fn into(self) -> String {
-        let mut s = String::with_capacity(
-            self.path.len() + KV_PATH_KEY_DELIMITER.len() + self.key.len() * 2,
-        );
-
-        s.push_str(&self.path);
-        s.push_str(KV_PATH_KEY_DELIMITER);
-        s.push_str(&encode_hex(&self.key));
-
-        s
+        self.to_string()
     }
 }
 
-/// KVKeys describes vec of KVKey structures
+/// KVKeys describes vec of KVKey structures.
+///
+/// Serialized as a comma-joined string of `path/hexkey` keys, the same way the Neutron CLI
+/// accepts them, e.g. `bank/0214...,staking/2114...`
+#[derive(Default, Clone, Debug, PartialEq, Eq)]
 pub struct KVKeys(pub Vec<KVKey>);
 
 impl KVKeys {
     /// Creates KVKeys from string
-    /// Returns None on failure
+    /// Returns None on failure, use [str::parse] to get the reason
     pub fn from_string<S: AsRef<str>>(s: S) -> Option<KVKeys> {
-        let split = s.as_ref().split(KV_KEYS_DELIMITER);
+        s.as_ref().parse().ok()
+    }
+}
+
+impl FromStr for KVKeys {
+    type Err = KVKeyParseError;
+
+    /// Parses KVKeys from comma-joined `path/hexkey` keys, an empty string is an empty KVKeys
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Ok(KVKeys::default());
+        }
 
-        Some(KVKeys(
-            split
-                .map(KVKey::from_string)
-                .collect::<Option<Vec<KVKey>>>()?,
-        ))
+        s.split(KV_KEYS_DELIMITER)
+            .map(KVKey::from_str)
+            .collect::<Result<Vec<KVKey>, KVKeyParseError>>()
+            .map(KVKeys)
+    }
+}
+
+impl fmt::Display for KVKeys {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, kv) in self.0.iter().enumerate() {
+            if i > 0 {
+                f.write_str(KV_KEYS_DELIMITER)?;
+            }
+            write!(f, "{}", kv)?;
+        }
+        Ok(())
     }
 }
 
 #[allow(clippy::from_over_into)]
 impl Into<String> for KVKeys {
     fn into(self) -> String {
-        self.0
-            .iter()
-            .map(|kv| kv.into())
-            .collect::<Vec<String>>()
-            .join(KV_KEYS_DELIMITER)
+        self.to_string()
+    }
+}
+
+impl Serialize for KVKeys {
+    /// Fails if any of the keys can't be parsed back, see [KVKey::validate]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        for kv in &self.0 {
+            kv.validate().map_err(ser::Error::custom)?;
+        }
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for KVKeys {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(de::Error::custom)
+    }
+}
+
+impl JsonSchema for KVKeys {
+    fn schema_name() -> String {
+        "KVKeys".to_string()
+    }
+
+    fn json_schema(gen: &mut SchemaGenerator) -> Schema {
+        String::json_schema(gen)
     }
 }
@@ -1,4 +1,5 @@
-use cosmwasm_std::{DecimalRangeExceeded, OverflowError, StdError};
+use crate::bindings::types::KVKeyParseError;
+use cosmwasm_std::{Coin, DecimalRangeExceeded, OverflowError, StdError};
 use serde_json_wasm;
 use thiserror::Error;
 
@@ -21,6 +22,9 @@ pub enum NeutronError {
     #[error("Serde JSON (Wasm) error")]
     SerdeJSONWasm(String),
 
+    #[error("Serde JSON error")]
+    SerdeJSON(String),
+
     #[error("address length should be max {max:?} bytes, got {actual:?}")]
     MaxAddrLength { max: usize, actual: usize },
 
@@ -44,6 +48,42 @@ pub enum NeutronError {
 
     #[error("Too many transaction filters, max allowed: {max:?}")]
     TooManyTransactionFilters { max: usize },
+
+    #[error("Too many messages in interchain transaction: {actual:?}, max allowed: {max:?}")]
+    TooManyTxMessages { max: u64, actual: usize },
+
+    #[error("Unsupported transaction filter field: {field:?}")]
+    UnsupportedTransactionFilterField { field: String },
+
+    #[error("Invalid transaction filter: {0}")]
+    InvalidTransactionFilter(String),
+
+    #[error("Insufficient funds for interchain query deposit: required {required}, available {available}")]
+    InsufficientQueryDeposit { required: Coin, available: Coin },
+
+    #[error("Interchain query with label {label:?} already exists")]
+    QueryLabelAlreadyExists { label: String },
+
+    #[error("Interchain query {query_id:?} result is stale (remote height {revision:?}-{remote_height:?}, local height {local_height:?}): {reason}")]
+    StaleQueryResult {
+        query_id: u64,
+        remote_height: u64,
+        revision: u64,
+        local_height: u64,
+        reason: String,
+    },
+
+    #[error("Invalid proof: {0}")]
+    InvalidProof(String),
+
+    #[error("Invalid KV key: {0}")]
+    InvalidKVKey(#[from] KVKeyParseError),
+
+    #[error("Invalid factory denom: {0}")]
+    InvalidFactoryDenom(String),
+
+    #[error("Message {type_url:?} of proposal must be serialized into a JSON object")]
+    InvalidProposalMessage { type_url: String },
 }
 
 impl From<serde_json_wasm::de::Error> for NeutronError {
@@ -51,3 +91,15 @@ impl From<serde_json_wasm::de::Error> for NeutronError {
         NeutronError::SerdeJSONWasm(e.to_string())
     }
 }
+
+impl From<serde_json_wasm::ser::Error> for NeutronError {
+    fn from(e: serde_json_wasm::ser::Error) -> Self {
+        NeutronError::SerdeJSONWasm(e.to_string())
+    }
+}
+
+impl From<serde_json::Error> for NeutronError {
+    fn from(e: serde_json::Error) -> Self {
+        NeutronError::SerdeJSON(e.to_string())
+    }
+}
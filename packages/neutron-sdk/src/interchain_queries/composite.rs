@@ -0,0 +1,149 @@
+use crate::bindings::msg::NeutronMsg;
+use crate::bindings::types::{KVKey, StorageValue};
+use crate::interchain_queries::types::{KVReconstruct, QueryPayload, QUERY_TYPE_KV_VALUE};
+use crate::{NeutronError, NeutronResult};
+use cosmwasm_std::StdError;
+
+/// A **data structure** that can be reconstructed from storage values of a composite KV query,
+/// i.e. a single registered query which keys are concatenated keys of several queries.
+///
+/// Implemented for tuples of [KVReconstruct] types. Every component is reconstructed from its own
+/// part of storage values, the amount of values in each part is described by **key_counts**:
+/// ```rust ignore
+/// let builder = CompositeQueryBuilder::new()
+///     .with_query(new_register_balance_query_msg(connection_id.clone(), addr.clone(), denom, 0)?)?
+///     .with_query(new_register_delegator_delegations_query_msg(connection_id, addr, validators, 0)?)?;
+/// KEY_COUNTS.save(deps.storage, &builder.key_counts())?;
+/// let msg = builder.build(update_period)?;
+///
+/// // later on
+/// let (balances, delegations): (Balances, Delegations) =
+///     query_composite_kv_result(deps, query_id, &KEY_COUNTS.load(deps.storage)?)?;
+/// ```
+pub trait KVReconstructComposite: Sized {
+    /// Reconstructs this value from the slice of **StorageValue**'s split into parts of **key_counts** values.
+    fn reconstruct_composite(kvs: &[StorageValue], key_counts: &[usize]) -> NeutronResult<Self>;
+}
+
+/// Splits **kvs** into consecutive parts of **key_counts** values each.
+/// Returns an error if the amount of values does not match the sum of **key_counts**.
+pub fn split_storage_values<'a>(
+    kvs: &'a [StorageValue],
+    key_counts: &[usize],
+) -> NeutronResult<Vec<&'a [StorageValue]>> {
+    let total: usize = key_counts.iter().sum();
+    if total != kvs.len() {
+        return Err(NeutronError::InvalidQueryResultFormat(format!(
+            "expected {} storage values, got {}",
+            total,
+            kvs.len()
+        )));
+    }
+
+    let mut parts = Vec::with_capacity(key_counts.len());
+    let mut rest = kvs;
+    for count in key_counts {
+        let (part, tail) = rest.split_at(*count);
+        parts.push(part);
+        rest = tail;
+    }
+
+    Ok(parts)
+}
+
+macro_rules! impl_kv_reconstruct_composite {
+    ($($component:ident $index:tt),+) => {
+        impl<$($component: KVReconstruct),+> KVReconstructComposite for ($($component,)+) {
+            fn reconstruct_composite(
+                kvs: &[StorageValue],
+                key_counts: &[usize],
+            ) -> NeutronResult<Self> {
+                let arity = [$($index),+].len();
+                if key_counts.len() != arity {
+                    return Err(NeutronError::InvalidQueryResultFormat(format!(
+                        "expected {} key counts, got {}",
+                        arity,
+                        key_counts.len()
+                    )));
+                }
+                let parts = split_storage_values(kvs, key_counts)?;
+
+                Ok(($($component::reconstruct(parts[$index])?,)+))
+            }
+        }
+    };
+}
+
+impl_kv_reconstruct_composite!(A 0, B 1);
+impl_kv_reconstruct_composite!(A 0, B 1, C 2);
+impl_kv_reconstruct_composite!(A 0, B 1, C 2, D 3);
+impl_kv_reconstruct_composite!(A 0, B 1, C 2, D 3, E 4);
+impl_kv_reconstruct_composite!(A 0, B 1, C 2, D 3, E 4, F 5);
+
+/// Builds a single KV query out of several KV register query messages (e.g. the ones created by
+/// `new_register_*_query_msg` helpers), so a contract pays a single deposit for all of them.
+/// See [KVReconstructComposite] to reconstruct the result of such a query.
+#[derive(Default, Clone, Debug, PartialEq, Eq)]
+pub struct CompositeQueryBuilder {
+    connection_id: Option<String>,
+    keys: Vec<KVKey>,
+    key_counts: Vec<usize>,
+}
+
+impl CompositeQueryBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends keys of **msg**, which must be a KV `NeutronMsg::RegisterInterchainQuery` for the
+    /// same connection as all the previously added messages. Update period of **msg** is ignored.
+    pub fn with_query(mut self, msg: NeutronMsg) -> NeutronResult<Self> {
+        match msg {
+            NeutronMsg::RegisterInterchainQuery {
+                query_type,
+                keys,
+                connection_id,
+                ..
+            } => {
+                if query_type != QUERY_TYPE_KV_VALUE {
+                    return Err(NeutronError::InvalidQueryType { query_type });
+                }
+                match &self.connection_id {
+                    Some(expected) if *expected != connection_id => {
+                        return Err(NeutronError::Std(StdError::generic_err(format!(
+                            "all queries must use connection {}, got {}",
+                            expected, connection_id
+                        ))))
+                    }
+                    _ => self.connection_id = Some(connection_id),
+                }
+
+                self.key_counts.push(keys.len());
+                self.keys.extend(keys);
+                Ok(self)
+            }
+            _ => Err(NeutronError::Std(StdError::generic_err(
+                "only RegisterInterchainQuery message can be added",
+            ))),
+        }
+    }
+
+    /// Returns amount of keys of every added message in the order of addition
+    pub fn key_counts(&self) -> Vec<usize> {
+        self.key_counts.clone()
+    }
+
+    /// Builds a register interchain query message with all the added keys
+    /// * **update_period** is used to say how often (in neutron blocks) the query must be updated.
+    pub fn build(self, update_period: u64) -> NeutronResult<NeutronMsg> {
+        let connection_id = self.connection_id.ok_or_else(|| {
+            StdError::generic_err("at least one query must be added to build a composite query")
+        })?;
+
+        NeutronMsg::register_interchain_query(
+            QueryPayload::KV(self.keys),
+            connection_id,
+            update_period,
+        )
+    }
+}
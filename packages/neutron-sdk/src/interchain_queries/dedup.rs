@@ -0,0 +1,183 @@
+use crate::bindings::types::Height;
+use cosmwasm_std::{Binary, Order, StdResult, Storage};
+use cw_storage_plus::{Bound, Map};
+use sha2::{Digest, Sha256};
+
+/// Default maximum amount of outdated records removed during a single [TxDedupLedger::record] call
+pub const DEFAULT_PRUNE_LIMIT: usize = 10;
+
+/// Deduplication ledger for transactions submitted to a contract via `SudoMsg::TxQueryResult`.
+///
+/// Relayers can submit the same transaction for a TX Interchain Query more than once. The ledger
+/// records a SHA-256 hash of every processed `TxRaw` per **query_id**, so the contract can process
+/// every transaction exactly once:
+/// ```rust ignore
+/// const PROCESSED_TXS: TxDedupLedger =
+///     TxDedupLedger::new("processed_txs", "processed_txs_heights", "processed_txs_floors")
+///         .with_retention(100_000);
+///
+/// SudoMsg::TxQueryResult { query_id, height, data } => {
+///     if !PROCESSED_TXS.record(deps.storage, query_id, &height, &data)? {
+///         // the transaction has already been processed, nothing to do
+///         return Ok(Response::default());
+///     }
+///     ...
+/// }
+/// ```
+///
+/// Records live forever unless **retention** (in remote chain blocks) is set. In this case the ledger
+/// keeps a per query floor of `highest recorded height - retention`: every [TxDedupLedger::record] call
+/// removes up to **prune_limit** records of the query below the floor, and transactions below the floor
+/// are never reported as new, because the ledger can't tell whether they were processed. Heights of
+/// an older revision of the remote chain are always below the floor of a newer one. It is a good idea
+/// to combine retention with a `tx.height` filter of the query.
+pub struct TxDedupLedger<'a> {
+    /// (query_id, tx hash) -> remote height of the transaction
+    txs: Map<'a, (u64, &'a [u8]), Height>,
+    /// (query_id, remote height, tx hash) index used to prune outdated records
+    heights: Map<'a, (u64, u128, &'a [u8]), ()>,
+    /// query_id -> lowest remote height still tracked
+    floors: Map<'a, u64, Height>,
+    retention: Option<u64>,
+    prune_limit: usize,
+}
+
+impl<'a> TxDedupLedger<'a> {
+    /// Creates a ledger which keeps records under **txs_namespace**, **heights_namespace** and
+    /// **floors_namespace** storage prefixes
+    pub const fn new(
+        txs_namespace: &'a str,
+        heights_namespace: &'a str,
+        floors_namespace: &'a str,
+    ) -> Self {
+        TxDedupLedger {
+            txs: Map::new(txs_namespace),
+            heights: Map::new(heights_namespace),
+            floors: Map::new(floors_namespace),
+            retention: None,
+            prune_limit: DEFAULT_PRUNE_LIMIT,
+        }
+    }
+
+    /// Sets amount of remote chain blocks during which transaction records are kept
+    pub const fn with_retention(mut self, retention: u64) -> Self {
+        self.retention = Some(retention);
+        self
+    }
+
+    /// Sets maximum amount of outdated records removed during a single [TxDedupLedger::record] call
+    pub const fn with_prune_limit(mut self, prune_limit: usize) -> Self {
+        self.prune_limit = prune_limit;
+        self
+    }
+
+    /// Records transaction **data** submitted for the query **query_id** at remote **height**.
+    /// Returns `true` if the transaction is new and `false` if it has already been recorded
+    /// or its height is below the floor of the query, i.e. it's not tracked anymore.
+    pub fn record(
+        &self,
+        storage: &mut dyn Storage,
+        query_id: u64,
+        height: &Height,
+        data: &Binary,
+    ) -> StdResult<bool> {
+        let hash = tx_hash(data);
+
+        if self.txs.has(storage, (query_id, &hash)) {
+            return Ok(false);
+        }
+        let floor = self.floor(storage, query_id)?;
+        if height_key(height) < height_key(&floor) {
+            return Ok(false);
+        }
+
+        self.txs.save(storage, (query_id, &hash), height)?;
+        self.heights
+            .save(storage, (query_id, height_key(height), &hash), &())?;
+
+        if let Some(retention) = self.retention {
+            let min_height = Height {
+                revision_number: height.revision_number,
+                revision_height: height.revision_height.saturating_sub(retention),
+            };
+            if height_key(&min_height) > height_key(&floor) {
+                self.prune(storage, query_id, &min_height, self.prune_limit)?;
+            }
+        }
+
+        Ok(true)
+    }
+
+    /// Checks whether transaction **data** has already been recorded for the query **query_id**
+    pub fn is_processed(&self, storage: &dyn Storage, query_id: u64, data: &Binary) -> bool {
+        self.txs.has(storage, (query_id, &tx_hash(data)))
+    }
+
+    /// Returns the lowest remote height of transactions the query **query_id** still tracks
+    pub fn floor(&self, storage: &dyn Storage, query_id: u64) -> StdResult<Height> {
+        Ok(self.floors.may_load(storage, query_id)?.unwrap_or_default())
+    }
+
+    /// Raises the floor of the query **query_id** to **min_height**, so transactions below it are
+    /// not reported as new anymore, and removes up to **limit** records below it.
+    /// Returns amount of removed records.
+    pub fn prune(
+        &self,
+        storage: &mut dyn Storage,
+        query_id: u64,
+        min_height: &Height,
+        limit: usize,
+    ) -> StdResult<usize> {
+        let min_height_key = height_key(min_height);
+        if min_height_key > height_key(&self.floor(storage, query_id)?) {
+            self.floors.save(storage, query_id, min_height)?;
+        }
+
+        let outdated = self
+            .heights
+            .sub_prefix(query_id)
+            .keys(
+                storage,
+                None,
+                Some(Bound::exclusive((min_height_key, &[][..]))),
+                Order::Ascending,
+            )
+            .take(limit)
+            .collect::<StdResult<Vec<(u128, Vec<u8>)>>>()?;
+
+        for (height, hash) in &outdated {
+            self.heights.remove(storage, (query_id, *height, hash));
+            self.txs.remove(storage, (query_id, hash));
+        }
+
+        Ok(outdated.len())
+    }
+
+    /// Removes all the records of the query **query_id**, e.g. after the query is removed
+    pub fn clear(&self, storage: &mut dyn Storage, query_id: u64) -> StdResult<()> {
+        let records = self
+            .txs
+            .prefix(query_id)
+            .range(storage, None, None, Order::Ascending)
+            .collect::<StdResult<Vec<(Vec<u8>, Height)>>>()?;
+
+        for (hash, height) in &records {
+            self.heights
+                .remove(storage, (query_id, height_key(height), hash));
+            self.txs.remove(storage, (query_id, hash));
+        }
+        self.floors.remove(storage, query_id);
+
+        Ok(())
+    }
+}
+
+/// Orders heights by revision first, the same way IBC does
+fn height_key(height: &Height) -> u128 {
+    (u128::from(height.revision_number) << 64) | u128::from(height.revision_height)
+}
+
+/// Calculates SHA-256 hash of raw transaction bytes
+fn tx_hash(data: &Binary) -> Vec<u8> {
+    Sha256::digest(data.as_slice()).to_vec()
+}
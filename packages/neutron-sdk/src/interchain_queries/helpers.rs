@@ -1,5 +1,9 @@
+use crate::bindings::types::StorageValue;
 use crate::errors::error::{NeutronError, NeutronResult};
 use crate::interchain_queries::types::{AddressBytes, MAX_ADDR_LEN};
+use cosmos_sdk_proto::traits::Message;
+use cosmwasm_std::from_slice;
+use serde::de::DeserializeOwned;
 
 /// Decodes a bech32 encoded string and converts to base64 encoded bytes
 /// <https://github.com/cosmos/cosmos-sdk/blob/ad9e5620fb3445c716e9de45cfcdb56e8f1745bf/types/bech32/bech32.go#L20>
@@ -29,3 +33,13 @@ pub fn length_prefix<AddrBytes: AsRef<[u8]>>(addr: AddrBytes) -> NeutronResult<V
 
     Ok(p)
 }
+
+/// Decodes a protobuf encoded value of the **StorageValue**, e.g. a Cosmos-SDK module store item
+pub fn decode_proto_storage_value<T: Message + Default>(kv: &StorageValue) -> NeutronResult<T> {
+    Ok(T::decode(kv.value.as_slice())?)
+}
+
+/// Decodes a JSON encoded value of the **StorageValue**, e.g. a wasm contract store item
+pub fn decode_json_storage_value<T: DeserializeOwned>(kv: &StorageValue) -> NeutronResult<T> {
+    Ok(from_slice(kv.value.as_slice())?)
+}
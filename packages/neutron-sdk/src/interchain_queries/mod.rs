@@ -1,6 +1,18 @@
+pub mod composite;
+pub mod dedup;
 pub mod helpers;
+#[cfg(feature = "proof")]
+pub mod proof;
 pub mod queries;
+pub mod registry;
 pub mod types;
 pub mod v045;
 
-pub use queries::{check_query_type, get_registered_query, query_kv_result};
+pub use queries::{
+    check_query_deposit, check_query_type, get_registered_query, query_composite_kv_result,
+    query_deposit, query_fresh_kv_result, query_interchain_queries_params, query_kv_result,
+    query_kv_result_with_meta, query_registered_queries,
+};
+
+#[cfg(test)]
+mod testing;
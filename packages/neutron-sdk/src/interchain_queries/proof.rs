@@ -0,0 +1,175 @@
+use crate::bindings::types::StorageValue;
+use crate::{NeutronError, NeutronResult};
+use ics23::commitment_proof::Proof;
+use ics23::{
+    calculate_existence_root, iavl_spec, tendermint_spec, verify_membership, verify_non_membership,
+    ExistenceProof, HashOp, HostFunctionsProvider,
+};
+use sha2::{Digest, Sha256, Sha512};
+
+pub use ics23::CommitmentProof;
+
+/// ICS-23 host functions backed by SHA-2 implementation of the SDK.
+///
+/// IAVL and Tendermint proof specs only use SHA-256, so other hash functions are not supported:
+/// they return an all-zero digest, so proofs using them never verify.
+pub struct Sha2HostFunctions;
+
+impl HostFunctionsProvider for Sha2HostFunctions {
+    fn sha2_256(message: &[u8]) -> [u8; 32] {
+        Sha256::digest(message).into()
+    }
+
+    fn sha2_512(message: &[u8]) -> [u8; 64] {
+        Sha512::digest(message).into()
+    }
+
+    fn sha2_512_truncated(message: &[u8]) -> [u8; 32] {
+        let digest = Sha512::digest(message);
+        let mut truncated = [0u8; 32];
+        truncated.copy_from_slice(&digest[..32]);
+        truncated
+    }
+
+    // The hash functions below are not supported. [verify_storage_value] rejects proofs using them
+    // with a proper error, any other caller gets an all-zero digest which never hashes to a valid
+    // root, so verification fails instead of aborting the contract.
+
+    fn keccak_256(_message: &[u8]) -> [u8; 32] {
+        [0; 32]
+    }
+
+    fn ripemd160(_message: &[u8]) -> [u8; 20] {
+        [0; 20]
+    }
+
+    fn blake2b_512(_message: &[u8]) -> [u8; 64] {
+        [0; 64]
+    }
+
+    fn blake2s_256(_message: &[u8]) -> [u8; 32] {
+        [0; 32]
+    }
+
+    fn blake3(_message: &[u8]) -> [u8; 32] {
+        [0; 32]
+    }
+}
+
+/// Verifies **storage_value** of a KV Interchain Query result against **app_hash** of remote chain,
+/// the same way the interchainqueries module does it on result submission.
+/// * **app_hash** is the app hash from the header of the block next to the block of the result;
+/// * **proofs** are ICS-23 proofs in the order of `ProofOps` of the value: an IAVL proof of the key
+///   in the module store followed by a simple merkle (Tendermint) proof of the module store root
+///   in the multistore.
+///
+/// An empty value is treated as absent and requires a non-existence proof of the key.
+pub fn verify_storage_value(
+    app_hash: &[u8],
+    storage_value: &StorageValue,
+    proofs: &[CommitmentProof],
+) -> NeutronResult<()> {
+    let [store_proof, multistore_proof] = proofs else {
+        return Err(NeutronError::InvalidProof(format!(
+            "expected 2 proofs, got {}",
+            proofs.len()
+        )));
+    };
+
+    ensure_supported_proof(store_proof)?;
+    ensure_supported_proof(multistore_proof)?;
+
+    let store_root = calculate_store_root(store_proof)?;
+    let valid = if storage_value.value.is_empty() {
+        verify_non_membership::<Sha2HostFunctions>(
+            store_proof,
+            &iavl_spec(),
+            &store_root,
+            storage_value.key.as_slice(),
+        )
+    } else {
+        verify_membership::<Sha2HostFunctions>(
+            store_proof,
+            &iavl_spec(),
+            &store_root,
+            storage_value.key.as_slice(),
+            storage_value.value.as_slice(),
+        )
+    };
+    if !valid {
+        return Err(NeutronError::InvalidProof(format!(
+            "failed to verify key {} in {} store",
+            storage_value.key, storage_value.storage_prefix
+        )));
+    }
+
+    if !verify_membership::<Sha2HostFunctions>(
+        multistore_proof,
+        &tendermint_spec(),
+        &app_hash.to_vec(),
+        storage_value.storage_prefix.as_bytes(),
+        &store_root,
+    ) {
+        return Err(NeutronError::InvalidProof(format!(
+            "failed to verify {} store root against app hash",
+            storage_value.storage_prefix
+        )));
+    }
+
+    Ok(())
+}
+
+/// Calculates the module store root from an existence or a non-existence proof of the key
+fn calculate_store_root(proof: &CommitmentProof) -> NeutronResult<Vec<u8>> {
+    let existence = match &proof.proof {
+        Some(Proof::Exist(existence)) => existence,
+        Some(Proof::Nonexist(non_existence)) => non_existence
+            .left
+            .as_ref()
+            .or(non_existence.right.as_ref())
+            .ok_or_else(|| {
+                NeutronError::InvalidProof("non-existence proof has no neighbours".to_string())
+            })?,
+        _ => {
+            return Err(NeutronError::InvalidProof(
+                "only existence and non-existence proofs are supported".to_string(),
+            ))
+        }
+    };
+
+    calculate_existence_root::<Sha2HostFunctions>(existence)
+        .map_err(|e| NeutronError::InvalidProof(e.to_string()))
+}
+
+/// Checks **proof** is an existence or a non-existence proof which uses only supported hash operations,
+/// so none of the unsupported [Sha2HostFunctions] is ever called
+fn ensure_supported_proof(proof: &CommitmentProof) -> NeutronResult<()> {
+    match &proof.proof {
+        Some(Proof::Exist(existence)) => ensure_supported_hash_ops(existence),
+        Some(Proof::Nonexist(non_existence)) => non_existence
+            .left
+            .iter()
+            .chain(non_existence.right.iter())
+            .try_for_each(ensure_supported_hash_ops),
+        _ => Err(NeutronError::InvalidProof(
+            "only existence and non-existence proofs are supported".to_string(),
+        )),
+    }
+}
+
+fn ensure_supported_hash_ops(proof: &ExistenceProof) -> NeutronResult<()> {
+    let supported = |op: i32| op == HashOp::NoHash as i32 || op == HashOp::Sha256 as i32;
+
+    let leaf_ops = proof
+        .leaf
+        .iter()
+        .flat_map(|leaf| [leaf.hash, leaf.prehash_key, leaf.prehash_value]);
+    let inner_ops = proof.path.iter().map(|inner| inner.hash);
+    if leaf_ops.chain(inner_ops).all(supported) {
+        Ok(())
+    } else {
+        Err(NeutronError::InvalidProof(
+            "only sha256 hash operations are supported".to_string(),
+        ))
+    }
+}
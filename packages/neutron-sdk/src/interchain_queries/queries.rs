@@ -1,10 +1,19 @@
 use crate::bindings::query::{
-    NeutronQuery, QueryRegisteredQueryResponse, QueryRegisteredQueryResultResponse,
+    NeutronQuery, PageRequest, PageResponse, QueryInterchainQueriesParamsResponse,
+    QueryRegisteredQueriesResponse, QueryRegisteredQueryResponse,
+    QueryRegisteredQueryResultResponse,
 };
+use crate::bindings::types::RegisteredQuery;
 use crate::errors::error::NeutronResult;
-use crate::interchain_queries::types::{KVReconstruct, QueryType};
+use crate::interchain_queries::composite::KVReconstructComposite;
+use crate::interchain_queries::types::{
+    KVReconstruct, QueryResultFreshness, QueryResultWithMeta, QueryType,
+};
 use crate::NeutronError;
-use cosmwasm_std::Deps;
+use cosmwasm_std::{Coin, Deps, Env, Uint128};
+
+/// Amount of registered queries requested at once by [query_registered_queries]
+pub const REGISTERED_QUERIES_PAGE_LIMIT: u64 = 100;
 
 /// Checks **actual** query type is **expected** query type
 pub fn check_query_type(actual: QueryType, expected: QueryType) -> NeutronResult<()> {
@@ -16,6 +25,54 @@ pub fn check_query_type(actual: QueryType, expected: QueryType) -> NeutronResult
     Ok(())
 }
 
+/// Queries parameters of the interchainqueries module
+pub fn query_interchain_queries_params(
+    deps: Deps<NeutronQuery>,
+) -> NeutronResult<QueryInterchainQueriesParamsResponse> {
+    let query = NeutronQuery::InterchainQueriesParams {};
+    Ok(deps.querier.query(&query.into())?)
+}
+
+/// Returns the deposit the module locks for registration of **queries_count** interchain queries.
+/// The deposit is taken from the contract balance, so the contract must hold it before sending
+/// `new_register_*_query_msg` messages, use [check_query_deposit] to make sure it does.
+pub fn query_deposit(deps: Deps<NeutronQuery>, queries_count: u64) -> NeutronResult<Vec<Coin>> {
+    query_interchain_queries_params(deps)?
+        .params
+        .query_deposit
+        .into_iter()
+        .map(|coin| {
+            Ok(Coin {
+                amount: coin.amount.checked_mul(Uint128::from(queries_count))?,
+                denom: coin.denom,
+            })
+        })
+        .collect()
+}
+
+/// Checks **contract_address** holds enough funds to register **queries_count** interchain queries
+/// and returns the required deposit, otherwise returns `NeutronError::InsufficientQueryDeposit`
+pub fn check_query_deposit(
+    deps: Deps<NeutronQuery>,
+    contract_address: impl Into<String>,
+    queries_count: u64,
+) -> NeutronResult<Vec<Coin>> {
+    let contract_address = contract_address.into();
+    let deposit = query_deposit(deps, queries_count)?;
+    for required in &deposit {
+        let available = deps
+            .querier
+            .query_balance(&contract_address, &required.denom)?;
+        if available.amount < required.amount {
+            return Err(NeutronError::InsufficientQueryDeposit {
+                required: required.clone(),
+                available,
+            });
+        }
+    }
+    Ok(deposit)
+}
+
 /// Queries registered query info
 pub fn get_registered_query(
     deps: Deps<NeutronQuery>,
@@ -39,6 +96,60 @@ pub fn query_kv_result<T: KVReconstruct>(
     KVReconstruct::reconstruct(&registered_query_result.result.kv_results)
 }
 
+/// Reads submitted raw KV values for composite Interchain Query with **query_id** from the storage and reconstructs
+/// every component of the result from its own **key_counts** values, see [KVReconstructComposite]
+pub fn query_composite_kv_result<T: KVReconstructComposite>(
+    deps: Deps<NeutronQuery>,
+    query_id: u64,
+    key_counts: &[usize],
+) -> NeutronResult<T> {
+    let registered_query_result = get_raw_interchain_query_result(deps, query_id)?;
+
+    KVReconstructComposite::reconstruct_composite(
+        &registered_query_result.result.kv_results,
+        key_counts,
+    )
+}
+
+/// Reads submitted raw KV values for Interchain Query with **query_id** from the storage and reconstructs the result
+/// along with remote and local heights of the result and connection of the query
+pub fn query_kv_result_with_meta<T: KVReconstruct>(
+    deps: Deps<NeutronQuery>,
+    query_id: u64,
+) -> NeutronResult<QueryResultWithMeta<T>> {
+    let registered_query = get_registered_query(deps, query_id)?.registered_query;
+    let registered_query_result = get_raw_interchain_query_result(deps, query_id)?;
+
+    Ok(QueryResultWithMeta {
+        result: KVReconstruct::reconstruct(&registered_query_result.result.kv_results)?,
+        remote_height: registered_query_result.result.height,
+        revision: registered_query_result.result.revision,
+        local_height: registered_query.last_submitted_result_local_height,
+        connection_id: registered_query.connection_id,
+    })
+}
+
+/// Reads submitted raw KV values for Interchain Query with **query_id** from the storage and reconstructs the result
+/// only if the result satisfies **freshness** bounds, otherwise returns `NeutronError::StaleQueryResult`
+pub fn query_fresh_kv_result<T: KVReconstruct>(
+    deps: Deps<NeutronQuery>,
+    env: &Env,
+    query_id: u64,
+    freshness: &QueryResultFreshness,
+) -> NeutronResult<T> {
+    let registered_query = get_registered_query(deps, query_id)?.registered_query;
+    let registered_query_result = get_raw_interchain_query_result(deps, query_id)?;
+
+    freshness.check(
+        query_id,
+        &registered_query_result.result,
+        registered_query.last_submitted_result_local_height,
+        env.block.height,
+    )?;
+
+    KVReconstruct::reconstruct(&registered_query_result.result.kv_results)
+}
+
 /// Queries raw interchain query result (raw KV storage values or transactions) from Interchain Queries Module.
 /// Usually it is better to implement [KVReconstruct] for your own type and then use [query_kv_result],
 /// but in cases when Rust forbids to implement foreign trait [KVReconstruct] for some foreign type,
@@ -53,3 +164,101 @@ pub fn get_raw_interchain_query_result(
     let res = deps.querier.query(&interchain_query.into())?;
     Ok(res)
 }
+
+/// Queries a single page of registered interchain queries
+/// * **owners** is a list of owners of the queries, queries of all owners are returned if empty;
+/// * **connection_id** is an IBC connection identifier of the queries, queries of all connections are returned if empty;
+/// * **pagination** describes the page to query.
+pub fn query_registered_queries_page(
+    deps: Deps<NeutronQuery>,
+    owners: Vec<String>,
+    connection_id: String,
+    pagination: PageRequest,
+) -> NeutronResult<QueryRegisteredQueriesResponse> {
+    let query = NeutronQuery::RegisteredInterchainQueries {
+        owners,
+        connection_id,
+        pagination,
+    };
+
+    let res: QueryRegisteredQueriesResponse = deps.querier.query(&query.into())?;
+    Ok(res)
+}
+
+/// Queries all registered interchain queries page by page, e.g. to audit and clean up queries of a contract:
+/// ```rust ignore
+/// for query in query_registered_queries(deps, vec![contract_address], String::new()) {
+///     let query = query?;
+///     ...
+/// }
+/// ```
+/// * **owners** is a list of owners of the queries, queries of all owners are returned if empty;
+/// * **connection_id** is an IBC connection identifier of the queries, queries of all connections are returned if empty.
+///
+/// Every next page is requested lazily, when all the queries of the previous one are consumed.
+pub fn query_registered_queries(
+    deps: Deps<NeutronQuery>,
+    owners: Vec<String>,
+    connection_id: String,
+) -> RegisteredQueriesIter {
+    RegisteredQueriesIter {
+        deps,
+        owners,
+        connection_id,
+        page: vec![].into_iter(),
+        next_page: Some(PageRequest::new().with_limit(REGISTERED_QUERIES_PAGE_LIMIT)),
+    }
+}
+
+/// Iterator over all registered interchain queries returned by [query_registered_queries]
+pub struct RegisteredQueriesIter<'a> {
+    deps: Deps<'a, NeutronQuery>,
+    owners: Vec<String>,
+    connection_id: String,
+    page: std::vec::IntoIter<RegisteredQuery>,
+    next_page: Option<PageRequest>,
+}
+
+impl<'a> Iterator for RegisteredQueriesIter<'a> {
+    type Item = NeutronResult<RegisteredQuery>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(query) = self.page.next() {
+                return Some(Ok(query));
+            }
+
+            let pagination = self.next_page.take()?;
+            let res = match query_registered_queries_page(
+                self.deps,
+                self.owners.clone(),
+                self.connection_id.clone(),
+                pagination.clone(),
+            ) {
+                Ok(res) => res,
+                Err(err) => return Some(Err(err)),
+            };
+
+            let fetched = res.registered_queries.len() as u64;
+            self.next_page = match res.pagination {
+                Some(PageResponse {
+                    next_key: Some(next_key),
+                    ..
+                }) if !next_key.is_empty() => Some(
+                    PageRequest::new()
+                        .with_key(next_key)
+                        .with_limit(pagination.limit),
+                ),
+                Some(_) => None,
+                // Older Neutron versions don't return pagination info, so we continue
+                // with offset until we get an incomplete page
+                None if fetched > 0 && fetched == pagination.limit => {
+                    let offset = pagination.offset + fetched;
+                    Some(pagination.with_offset(offset))
+                }
+                None => None,
+            };
+            self.page = res.registered_queries.into_iter();
+        }
+    }
+}
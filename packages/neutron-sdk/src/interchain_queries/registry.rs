@@ -0,0 +1,385 @@
+use crate::bindings::msg::{MsgRegisterInterchainQueryResponse, NeutronMsg};
+use crate::bindings::query::NeutronQuery;
+use crate::bindings::types::{KVKey, RegisteredQuery};
+use crate::interchain_queries::get_registered_query;
+use crate::interchain_queries::types::{
+    QueryType, TransactionFilterItem, QUERY_TYPE_KV_VALUE, QUERY_TYPE_TX_VALUE,
+};
+use crate::{NeutronError, NeutronResult};
+use cosmwasm_std::{
+    from_slice, Coin, DepsMut, Order, Reply, StdError, StdResult, Storage, SubMsg, Uint128,
+};
+use cw_storage_plus::{Bound, Deque, Map};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Interchain query tracked by a [QueryRegistry]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct TrackedQuery {
+    /// **id** is an identifier of the query assigned by the interchainqueries module
+    pub id: u64,
+    /// **label** is a contract defined unique name of the query
+    pub label: String,
+    /// **query_type** is a query type identifier ('tx' or 'kv' for now)
+    pub query_type: QueryType,
+    /// **keys** is the KV-storage keys of the query
+    pub keys: Vec<KVKey>,
+    /// **transactions_filter** is the filter of the TX query
+    pub transactions_filter: String,
+    /// **connection_id** is an IBC connection identifier between Neutron and remote chain
+    pub connection_id: String,
+    /// **update_period** is how often (in neutron blocks) the query must be updated
+    pub update_period: u64,
+    /// **deposit** is an amount of coins locked by the module for the query.
+    /// The deposit is returned to the contract when the query is removed.
+    pub deposit: Vec<Coin>,
+}
+
+/// Registration which waits for a reply with an identifier of the query
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+struct PendingQuery {
+    label: String,
+    query_type: QueryType,
+    keys: Vec<KVKey>,
+    transactions_filter: String,
+    connection_id: String,
+    update_period: u64,
+}
+
+impl PendingQuery {
+    /// Checks whether the **registered_query** was registered with this registration
+    fn matches(&self, registered_query: &RegisteredQuery) -> bool {
+        self.query_type == registered_query.query_type
+            && self.keys == registered_query.keys
+            && self.transactions_filter == registered_query.transactions_filter
+            && self.connection_id == registered_query.connection_id
+            && self.update_period == registered_query.update_period
+    }
+}
+
+/// Local bookkeeping layer for Interchain Queries registered by a contract.
+///
+/// The interchainqueries module returns an identifier of a newly registered query only in the
+/// response of `RegisterInterchainQuery` message. The registry wraps the message into a
+/// reply-on-success submessage, catches the identifier in the reply and stores the query under
+/// a contract defined **label**. Replies carry only the identifier, so the registry fetches
+/// the registered query and attributes it to the oldest pending registration with the same
+/// parameters, i.e. submessages may be dispatched in any order or not dispatched at all:
+/// ```rust ignore
+/// const QUERIES: QueryRegistry =
+///     QueryRegistry::new("queries", "queries_labels", "queries_pending", REGISTER_QUERY_REPLY_ID);
+///
+/// // execute
+/// let msg = new_register_balance_query_msg(connection_id, addr, denom, update_period)?;
+/// let submsg = QUERIES.register(deps.storage, "balance", msg)?;
+/// Ok(Response::new().add_submessage(submsg))
+///
+/// // reply
+/// REGISTER_QUERY_REPLY_ID => {
+///     QUERIES.handle_reply(deps, msg)?;
+///     Ok(Response::new())
+/// }
+///
+/// // later on
+/// let balance_query = QUERIES.load_by_label(deps.storage, "balance")?;
+/// let remove_msg = QUERIES.remove(deps.storage, balance_query.id)?;
+/// ```
+///
+/// Registrations whose submessages were never dispatched keep their labels reserved until
+/// [QueryRegistry::clear_pending] is called.
+///
+/// Removal of a query returns its deposit to the contract, [QueryRegistry::total_deposit] shows
+/// how much coins are currently locked in the registered queries.
+pub struct QueryRegistry<'a> {
+    /// query id -> query
+    queries: Map<'a, u64, TrackedQuery>,
+    /// label -> query id
+    labels: Map<'a, &'a str, u64>,
+    /// registrations waiting for replies in the order of [QueryRegistry::register] calls
+    pending: Deque<'a, PendingQuery>,
+    reply_id: u64,
+}
+
+impl<'a> QueryRegistry<'a> {
+    /// Creates a registry which keeps queries under **queries_namespace**, **labels_namespace** and
+    /// **pending_namespace** storage prefixes and registers queries with submessages with **reply_id**
+    pub const fn new(
+        queries_namespace: &'a str,
+        labels_namespace: &'a str,
+        pending_namespace: &'a str,
+        reply_id: u64,
+    ) -> Self {
+        QueryRegistry {
+            queries: Map::new(queries_namespace),
+            labels: Map::new(labels_namespace),
+            pending: Deque::new(pending_namespace),
+            reply_id,
+        }
+    }
+
+    /// Returns an identifier of replies the registry handles
+    pub fn reply_id(&self) -> u64 {
+        self.reply_id
+    }
+
+    /// Wraps **msg** (must be `NeutronMsg::RegisterInterchainQuery`) into a reply-on-success submessage.
+    /// The query is tracked under the **label** after the reply is handled by [QueryRegistry::handle_reply].
+    pub fn register(
+        &self,
+        storage: &mut dyn Storage,
+        label: impl Into<String>,
+        msg: NeutronMsg,
+    ) -> NeutronResult<SubMsg<NeutronMsg>> {
+        let label = label.into();
+        if self.labels.has(storage, &label) || self.is_pending(storage, &label)? {
+            return Err(NeutronError::QueryLabelAlreadyExists { label });
+        }
+
+        let pending = match &msg {
+            NeutronMsg::RegisterInterchainQuery {
+                query_type,
+                keys,
+                transactions_filter,
+                connection_id,
+                update_period,
+            } => PendingQuery {
+                label,
+                query_type: parse_query_type(query_type)?,
+                keys: keys.clone(),
+                transactions_filter: transactions_filter.clone(),
+                connection_id: connection_id.clone(),
+                update_period: *update_period,
+            },
+            _ => {
+                return Err(NeutronError::Std(StdError::generic_err(
+                    "only RegisterInterchainQuery message can be registered",
+                )))
+            }
+        };
+        self.pending.push_back(storage, &pending)?;
+
+        Ok(SubMsg::reply_on_success(msg, self.reply_id))
+    }
+
+    /// Handles a reply to a registration submessage: reads the identifier of the registered query,
+    /// fetches the query, matches it with a pending registration and stores it in the registry
+    pub fn handle_reply(
+        &self,
+        deps: DepsMut<NeutronQuery>,
+        msg: Reply,
+    ) -> NeutronResult<TrackedQuery> {
+        if msg.id != self.reply_id {
+            return Err(NeutronError::InvalidReplyID(msg.id));
+        }
+
+        let data = msg
+            .result
+            .into_result()
+            .map_err(StdError::generic_err)?
+            .data
+            .ok_or_else(|| StdError::generic_err("no result"))?;
+        let resp: MsgRegisterInterchainQueryResponse = from_slice(data.as_slice())?;
+
+        let registered_query = get_registered_query(deps.as_ref(), resp.id)?.registered_query;
+        let pending = self
+            .take_pending(deps.storage, &registered_query)?
+            .ok_or_else(|| {
+                StdError::generic_err(format!(
+                    "no pending registration of interchain query {}",
+                    resp.id
+                ))
+            })?;
+
+        let query = TrackedQuery {
+            id: resp.id,
+            label: pending.label,
+            query_type: pending.query_type,
+            keys: pending.keys,
+            transactions_filter: pending.transactions_filter,
+            connection_id: pending.connection_id,
+            update_period: pending.update_period,
+            deposit: registered_query.deposit,
+        };
+        self.queries.save(deps.storage, query.id, &query)?;
+        self.labels.save(deps.storage, &query.label, &query.id)?;
+
+        Ok(query)
+    }
+
+    /// Loads the query **query_id**
+    pub fn load(&self, storage: &dyn Storage, query_id: u64) -> StdResult<TrackedQuery> {
+        self.queries.load(storage, query_id)
+    }
+
+    /// Loads the query **query_id** if it is tracked by the registry
+    pub fn may_load(
+        &self,
+        storage: &dyn Storage,
+        query_id: u64,
+    ) -> StdResult<Option<TrackedQuery>> {
+        self.queries.may_load(storage, query_id)
+    }
+
+    /// Loads the query registered under the **label**
+    pub fn load_by_label(&self, storage: &dyn Storage, label: &str) -> StdResult<TrackedQuery> {
+        let query_id = self.labels.load(storage, label)?;
+        self.queries.load(storage, query_id)
+    }
+
+    /// Returns an identifier of the query registered under the **label**
+    pub fn query_id(&self, storage: &dyn Storage, label: &str) -> StdResult<Option<u64>> {
+        self.labels.may_load(storage, label)
+    }
+
+    /// Returns up to **limit** tracked queries ordered by ids, starting after **start_after**
+    pub fn range(
+        &self,
+        storage: &dyn Storage,
+        start_after: Option<u64>,
+        limit: Option<usize>,
+    ) -> StdResult<Vec<TrackedQuery>> {
+        let iter = self
+            .queries
+            .range(
+                storage,
+                start_after.map(Bound::exclusive),
+                None,
+                Order::Ascending,
+            )
+            .map(|item| item.map(|(_, query)| query));
+
+        match limit {
+            Some(limit) => iter.take(limit).collect(),
+            None => iter.collect(),
+        }
+    }
+
+    /// Builds `NeutronMsg::UpdateInterchainQuery` for the query **query_id** and updates the query
+    /// in the registry accordingly
+    pub fn update(
+        &self,
+        storage: &mut dyn Storage,
+        query_id: u64,
+        new_keys: Option<Vec<KVKey>>,
+        new_update_period: Option<u64>,
+        new_transactions_filter: Option<Vec<TransactionFilterItem>>,
+    ) -> NeutronResult<NeutronMsg> {
+        let mut query = self.queries.load(storage, query_id)?;
+
+        let msg = NeutronMsg::update_interchain_query(
+            query_id,
+            new_keys,
+            new_update_period,
+            new_transactions_filter,
+        )?;
+        if let NeutronMsg::UpdateInterchainQuery {
+            new_keys,
+            new_update_period,
+            new_transactions_filter,
+            ..
+        } = &msg
+        {
+            if let Some(keys) = new_keys {
+                query.keys = keys.clone();
+            }
+            if let Some(update_period) = new_update_period {
+                query.update_period = *update_period;
+            }
+            if let Some(transactions_filter) = new_transactions_filter {
+                query.transactions_filter = transactions_filter.clone();
+            }
+        }
+        self.queries.save(storage, query_id, &query)?;
+
+        Ok(msg)
+    }
+
+    /// Removes the query **query_id** from the registry and builds `NeutronMsg::RemoveInterchainQuery`
+    /// which returns the deposit of the query to the contract
+    pub fn remove(&self, storage: &mut dyn Storage, query_id: u64) -> NeutronResult<NeutronMsg> {
+        let query = self.queries.load(storage, query_id)?;
+
+        self.queries.remove(storage, query_id);
+        self.labels.remove(storage, &query.label);
+
+        Ok(NeutronMsg::remove_interchain_query(query_id))
+    }
+
+    /// Removes the query registered under the **label**, see [QueryRegistry::remove]
+    pub fn remove_by_label(
+        &self,
+        storage: &mut dyn Storage,
+        label: &str,
+    ) -> NeutronResult<NeutronMsg> {
+        let query_id = self.labels.load(storage, label)?;
+        self.remove(storage, query_id)
+    }
+
+    /// Returns the sum of deposits of all the tracked queries grouped by denom
+    pub fn total_deposit(&self, storage: &dyn Storage) -> NeutronResult<Vec<Coin>> {
+        let mut total: BTreeMap<String, Uint128> = BTreeMap::new();
+        for item in self.queries.range(storage, None, None, Order::Ascending) {
+            let (_, query) = item?;
+            for coin in query.deposit {
+                let amount = total.entry(coin.denom).or_default();
+                *amount = amount.checked_add(coin.amount)?;
+            }
+        }
+
+        Ok(total
+            .into_iter()
+            .map(|(denom, amount)| Coin { denom, amount })
+            .collect())
+    }
+
+    /// Forgets all the registrations waiting for replies. Replies are handled within the transaction
+    /// which dispatched the registration submessages, so it's safe to call it in any other transaction,
+    /// e.g. to release labels of registrations whose submessages were never dispatched
+    pub fn clear_pending(&self, storage: &mut dyn Storage) -> StdResult<()> {
+        while self.pending.pop_front(storage)?.is_some() {}
+        Ok(())
+    }
+
+    /// Removes the oldest pending registration the **registered_query** was registered with
+    fn take_pending(
+        &self,
+        storage: &mut dyn Storage,
+        registered_query: &RegisteredQuery,
+    ) -> StdResult<Option<PendingQuery>> {
+        let mut taken = None;
+        let mut rest = vec![];
+        while let Some(pending) = self.pending.pop_front(storage)? {
+            if taken.is_none() && pending.matches(registered_query) {
+                taken = Some(pending);
+            } else {
+                rest.push(pending);
+            }
+        }
+        for pending in rest {
+            self.pending.push_back(storage, &pending)?;
+        }
+        Ok(taken)
+    }
+
+    fn is_pending(&self, storage: &dyn Storage, label: &str) -> StdResult<bool> {
+        for item in self.pending.iter(storage)? {
+            if item?.label == label {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+}
+
+fn parse_query_type(query_type: &str) -> NeutronResult<QueryType> {
+    match query_type {
+        QUERY_TYPE_KV_VALUE => Ok(QueryType::KV),
+        QUERY_TYPE_TX_VALUE => Ok(QueryType::TX),
+        _ => Err(NeutronError::InvalidQueryType {
+            query_type: query_type.to_string(),
+        }),
+    }
+}
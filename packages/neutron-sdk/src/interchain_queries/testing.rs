@@ -0,0 +1,1531 @@
+use crate::bindings::msg::{MsgRegisterInterchainQueryResponse, NeutronMsg};
+use crate::bindings::query::{
+    InterchainQueriesParams, NeutronQuery, PageRequest, PageResponse,
+    QueryRegisteredQueriesResponse, QueryRegisteredQueryResponse,
+    QueryRegisteredQueryResultResponse,
+};
+use crate::bindings::types::{Height, InterchainQueryResult, KVKey, RegisteredQuery, StorageValue};
+use crate::interchain_queries::composite::{CompositeQueryBuilder, KVReconstructComposite};
+use crate::interchain_queries::dedup::TxDedupLedger;
+use crate::interchain_queries::queries::REGISTERED_QUERIES_PAGE_LIMIT;
+use crate::interchain_queries::registry::{QueryRegistry, TrackedQuery};
+use crate::interchain_queries::types::{
+    validate_transactions_filter, KVReconstruct, QueryPayload, QueryResultFreshness,
+    QueryResultWithMeta, QueryType, TransactionFilterItem, TransactionFilterOp,
+    TransactionFilterValue, UpdateInterchainQuery, MAX_TX_FILTERS, MAX_TX_FILTER_INT,
+};
+use crate::interchain_queries::v045::register_queries::{
+    new_register_balance_query_msg, new_register_staking_validators_query_msg,
+    new_register_transfers_query_msg,
+};
+use crate::interchain_queries::v045::types::Balances;
+use crate::interchain_queries::{
+    check_query_deposit, query_deposit, query_fresh_kv_result, query_interchain_queries_params,
+    query_kv_result_with_meta, query_registered_queries,
+};
+use crate::{NeutronError, NeutronResult};
+use cosmos_sdk_proto::traits::Message;
+use cosmwasm_std::testing::{mock_env, MockApi, MockQuerier, MockStorage};
+use cosmwasm_std::{
+    coin, to_binary, Binary, Coin, ContractResult, OwnedDeps, Reply, StdError, SubMsg,
+    SubMsgResponse, SubMsgResult, SystemResult, Uint128,
+};
+use std::marker::PhantomData;
+
+fn height(revision_height: u64) -> Height {
+    Height {
+        revision_number: 0,
+        revision_height,
+    }
+}
+
+#[test]
+fn test_tx_dedup_ledger_record() {
+    let ledger = TxDedupLedger::new("txs", "txs_heights", "txs_floors");
+    let mut storage = MockStorage::new();
+    let tx = Binary::from(b"tx".as_slice());
+    let another_tx = Binary::from(b"another tx".as_slice());
+
+    assert!(!ledger.is_processed(&storage, 1, &tx));
+    assert!(ledger.record(&mut storage, 1, &height(10), &tx).unwrap());
+    assert!(ledger.is_processed(&storage, 1, &tx));
+
+    // the same transaction submitted again is not new, even at another height
+    assert!(!ledger.record(&mut storage, 1, &height(10), &tx).unwrap());
+    assert!(!ledger.record(&mut storage, 1, &height(11), &tx).unwrap());
+
+    // records are kept per query
+    assert!(ledger.record(&mut storage, 2, &height(10), &tx).unwrap());
+    assert!(ledger
+        .record(&mut storage, 1, &height(10), &another_tx)
+        .unwrap());
+
+    ledger.clear(&mut storage, 1).unwrap();
+    assert!(!ledger.is_processed(&storage, 1, &tx));
+    assert!(!ledger.is_processed(&storage, 1, &another_tx));
+    assert!(ledger.is_processed(&storage, 2, &tx));
+}
+
+#[test]
+fn test_tx_dedup_ledger_prune() {
+    let ledger = TxDedupLedger::new("txs", "txs_heights", "txs_floors")
+        .with_retention(100)
+        .with_prune_limit(2);
+    let mut storage = MockStorage::new();
+    let txs: Vec<Binary> = (0u8..4).map(|i| Binary::from(vec![i])).collect();
+
+    for (i, tx) in txs.iter().enumerate() {
+        assert!(ledger
+            .record(&mut storage, 1, &height(10 + i as u64), tx)
+            .unwrap());
+    }
+    assert!(ledger
+        .record(&mut storage, 2, &height(10), &txs[0])
+        .unwrap());
+
+    // records older than 112 - 100 are pruned, but not more than 2 at once
+    let new_tx = Binary::from(b"new tx".as_slice());
+    assert!(ledger
+        .record(&mut storage, 1, &height(112), &new_tx)
+        .unwrap());
+    assert!(!ledger.is_processed(&storage, 1, &txs[0]));
+    assert!(!ledger.is_processed(&storage, 1, &txs[1]));
+    assert!(ledger.is_processed(&storage, 1, &txs[2]));
+    assert!(ledger.is_processed(&storage, 1, &txs[3]));
+    assert!(ledger.is_processed(&storage, 1, &new_tx));
+    // other queries are not affected
+    assert!(ledger.is_processed(&storage, 2, &txs[0]));
+
+    assert_eq!(ledger.prune(&mut storage, 1, &height(13), 10).unwrap(), 1);
+    assert!(!ledger.is_processed(&storage, 1, &txs[2]));
+    assert!(ledger.is_processed(&storage, 1, &txs[3]));
+    assert_eq!(ledger.prune(&mut storage, 1, &height(13), 10).unwrap(), 0);
+    assert_eq!(ledger.floor(&storage, 1).unwrap(), height(13));
+
+    // pruned transactions and any other transactions below the floor are not new
+    assert!(!ledger
+        .record(&mut storage, 1, &height(10), &txs[0])
+        .unwrap());
+    assert!(!ledger
+        .record(&mut storage, 1, &height(12), &txs[2])
+        .unwrap());
+    assert!(!ledger
+        .record(
+            &mut storage,
+            1,
+            &height(11),
+            &Binary::from(b"old tx".as_slice())
+        )
+        .unwrap());
+    assert!(!ledger.is_processed(&storage, 1, &txs[0]));
+    // the floor is not lowered by older submissions or manual pruning
+    assert_eq!(ledger.prune(&mut storage, 1, &height(5), 10).unwrap(), 0);
+    assert_eq!(ledger.floor(&storage, 1).unwrap(), height(13));
+
+    ledger.clear(&mut storage, 1).unwrap();
+    assert_eq!(ledger.floor(&storage, 1).unwrap(), height(0));
+    assert!(ledger
+        .record(&mut storage, 1, &height(10), &txs[0])
+        .unwrap());
+}
+
+#[test]
+fn test_tx_dedup_ledger_revisions() {
+    let ledger = TxDedupLedger::new("txs", "txs_heights", "txs_floors").with_retention(100);
+    let mut storage = MockStorage::new();
+    let old_revision = |revision_height| Height {
+        revision_number: 1,
+        revision_height,
+    };
+    let new_revision = |revision_height| Height {
+        revision_number: 2,
+        revision_height,
+    };
+    let txs: Vec<Binary> = (0u8..3).map(|i| Binary::from(vec![i])).collect();
+
+    assert!(ledger
+        .record(&mut storage, 1, &old_revision(1000), &txs[0])
+        .unwrap());
+    // heights of a new revision start over, but they are higher than any height of the old one,
+    // so the old revision is below the floor right away
+    assert!(ledger
+        .record(&mut storage, 1, &new_revision(10), &txs[1])
+        .unwrap());
+    assert_eq!(ledger.floor(&storage, 1).unwrap(), new_revision(0));
+    assert!(!ledger.is_processed(&storage, 1, &txs[0]));
+    assert!(ledger
+        .record(&mut storage, 1, &new_revision(200), &txs[2])
+        .unwrap());
+    assert_eq!(ledger.floor(&storage, 1).unwrap(), new_revision(100));
+    assert!(!ledger.is_processed(&storage, 1, &txs[1]));
+
+    // the old revision is below the floor even though its height is higher
+    assert!(!ledger
+        .record(&mut storage, 1, &old_revision(1000), &txs[0])
+        .unwrap());
+    assert!(!ledger
+        .record(&mut storage, 1, &new_revision(10), &txs[1])
+        .unwrap());
+}
+
+fn build_registered_query(id: u64) -> RegisteredQuery {
+    RegisteredQuery {
+        id,
+        owner: "owner".to_string(),
+        keys: vec![],
+        query_type: QueryType::KV,
+        transactions_filter: "".to_string(),
+        connection_id: "connection-0".to_string(),
+        update_period: 1,
+        last_submitted_result_local_height: 0,
+        last_submitted_result_remote_height: Height::default(),
+        deposit: vec![],
+        submit_timeout: 0,
+        registered_at_height: 0,
+    }
+}
+
+fn mock_dependencies_with_registered_queries(
+    handler: impl Fn(&PageRequest) -> QueryRegisteredQueriesResponse + 'static,
+) -> OwnedDeps<MockStorage, MockApi, MockQuerier<NeutronQuery>, NeutronQuery> {
+    let querier =
+        MockQuerier::<NeutronQuery>::new(&[]).with_custom_handler(move |query| match query {
+            NeutronQuery::RegisteredInterchainQueries { pagination, .. } => {
+                SystemResult::Ok(ContractResult::Ok(to_binary(&handler(pagination)).unwrap()))
+            }
+            _ => unimplemented!(),
+        });
+
+    OwnedDeps {
+        storage: MockStorage::default(),
+        api: MockApi::default(),
+        querier,
+        custom_query_type: PhantomData,
+    }
+}
+
+#[test]
+fn test_query_registered_queries_by_key() {
+    // 5 queries split into pages of 2 queries, next key is an id of the next query
+    let deps = mock_dependencies_with_registered_queries(|pagination| {
+        assert_eq!(pagination.limit, REGISTERED_QUERIES_PAGE_LIMIT);
+        let start = pagination.key.first().copied().unwrap_or(1) as u64;
+        let end = (start + 2).min(6);
+        QueryRegisteredQueriesResponse {
+            registered_queries: (start..end).map(build_registered_query).collect(),
+            pagination: Some(PageResponse {
+                next_key: (end < 6).then(|| Binary::from(vec![end as u8])),
+                total: 0,
+            }),
+        }
+    });
+
+    let queries = query_registered_queries(deps.as_ref(), vec![], "".to_string())
+        .collect::<NeutronResult<Vec<RegisteredQuery>>>()
+        .unwrap();
+    assert_eq!(
+        queries,
+        (1..6).map(build_registered_query).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn test_query_registered_queries_without_pagination_info() {
+    // older Neutron versions don't return pagination info
+    let total = REGISTERED_QUERIES_PAGE_LIMIT * 2 + 10;
+    let deps = mock_dependencies_with_registered_queries(move |pagination| {
+        let start = pagination.offset;
+        let end = (start + pagination.limit).min(total);
+        QueryRegisteredQueriesResponse {
+            registered_queries: (start..end).map(build_registered_query).collect(),
+            pagination: None,
+        }
+    });
+
+    let queries = query_registered_queries(deps.as_ref(), vec![], "".to_string())
+        .collect::<NeutronResult<Vec<RegisteredQuery>>>()
+        .unwrap();
+    assert_eq!(
+        queries,
+        (0..total).map(build_registered_query).collect::<Vec<_>>()
+    );
+}
+
+const REGISTER_QUERY_REPLY_ID: u64 = 7;
+
+fn mock_dependencies_with_deposits(
+    registered_keys: &'static [(u64, &'static [u8])],
+) -> OwnedDeps<MockStorage, MockApi, MockQuerier<NeutronQuery>, NeutronQuery> {
+    // every registered query is registered with `register_kv_query_msg` with a key from
+    // **registered_keys** and has a deposit of `{query_id}untrn`
+    let querier =
+        MockQuerier::<NeutronQuery>::new(&[]).with_custom_handler(move |query| match query {
+            NeutronQuery::RegisteredInterchainQuery { query_id } => {
+                let mut registered_query = build_registered_query(*query_id);
+                registered_query.keys = registered_keys
+                    .iter()
+                    .filter(|(id, _)| id == query_id)
+                    .map(|(_, key)| KVKey {
+                        path: "bank".to_string(),
+                        key: Binary::from(*key),
+                    })
+                    .collect();
+                registered_query.update_period = 10;
+                registered_query.deposit = vec![coin(*query_id as u128, "untrn")];
+                SystemResult::Ok(ContractResult::Ok(
+                    to_binary(&QueryRegisteredQueryResponse { registered_query }).unwrap(),
+                ))
+            }
+            _ => unimplemented!(),
+        });
+
+    OwnedDeps {
+        storage: MockStorage::default(),
+        api: MockApi::default(),
+        querier,
+        custom_query_type: PhantomData,
+    }
+}
+
+fn register_kv_query_msg(key: &[u8]) -> NeutronMsg {
+    NeutronMsg::register_interchain_query(
+        QueryPayload::KV(vec![KVKey {
+            path: "bank".to_string(),
+            key: Binary::from(key),
+        }]),
+        "connection-0".to_string(),
+        10,
+    )
+    .unwrap()
+}
+
+fn register_query_reply(query_id: u64) -> Reply {
+    Reply {
+        id: REGISTER_QUERY_REPLY_ID,
+        result: SubMsgResult::Ok(SubMsgResponse {
+            events: vec![],
+            data: Some(to_binary(&MsgRegisterInterchainQueryResponse { id: query_id }).unwrap()),
+        }),
+    }
+}
+
+#[test]
+fn test_query_registry_register() {
+    let registry = QueryRegistry::new(
+        "queries",
+        "queries_labels",
+        "queries_pending",
+        REGISTER_QUERY_REPLY_ID,
+    );
+    let mut deps = mock_dependencies_with_deposits(&[(3, b"balance"), (5, b"delegations")]);
+
+    let msg = register_kv_query_msg(b"balance");
+    let submsg = registry
+        .register(deps.as_mut().storage, "balance", msg.clone())
+        .unwrap();
+    assert_eq!(
+        submsg,
+        SubMsg::reply_on_success(msg, REGISTER_QUERY_REPLY_ID)
+    );
+
+    // labels are unique, including the ones waiting for a reply
+    assert_eq!(
+        registry
+            .register(
+                deps.as_mut().storage,
+                "balance",
+                register_kv_query_msg(b"another")
+            )
+            .unwrap_err(),
+        NeutronError::QueryLabelAlreadyExists {
+            label: "balance".to_string()
+        }
+    );
+    // only registration messages are accepted
+    assert!(registry
+        .register(
+            deps.as_mut().storage,
+            "remove",
+            NeutronMsg::remove_interchain_query(1)
+        )
+        .is_err());
+
+    registry
+        .register(
+            deps.as_mut().storage,
+            "delegations",
+            register_kv_query_msg(b"delegations"),
+        )
+        .unwrap();
+
+    // replies of other submessages are rejected
+    let mut reply = register_query_reply(1);
+    reply.id = 1;
+    assert_eq!(
+        registry.handle_reply(deps.as_mut(), reply).unwrap_err(),
+        NeutronError::InvalidReplyID(1)
+    );
+
+    // replies are attributed to the registrations by the parameters of the registered queries
+    let balance_query = registry
+        .handle_reply(deps.as_mut(), register_query_reply(3))
+        .unwrap();
+    assert_eq!(
+        balance_query,
+        TrackedQuery {
+            id: 3,
+            label: "balance".to_string(),
+            query_type: QueryType::KV,
+            keys: vec![KVKey {
+                path: "bank".to_string(),
+                key: Binary::from(b"balance".as_slice()),
+            }],
+            transactions_filter: "".to_string(),
+            connection_id: "connection-0".to_string(),
+            update_period: 10,
+            deposit: vec![coin(3, "untrn")],
+        }
+    );
+    let delegations_query = registry
+        .handle_reply(deps.as_mut(), register_query_reply(5))
+        .unwrap();
+    assert_eq!(delegations_query.label, "delegations");
+    assert!(registry
+        .handle_reply(deps.as_mut(), register_query_reply(6))
+        .is_err());
+
+    assert_eq!(
+        registry.load_by_label(&deps.storage, "balance").unwrap(),
+        balance_query
+    );
+    assert_eq!(
+        registry.query_id(&deps.storage, "delegations").unwrap(),
+        Some(5)
+    );
+    assert_eq!(
+        registry.range(&deps.storage, None, None).unwrap(),
+        vec![balance_query.clone(), delegations_query.clone()]
+    );
+    assert_eq!(
+        registry.range(&deps.storage, Some(3), Some(1)).unwrap(),
+        vec![delegations_query]
+    );
+    assert_eq!(
+        registry.total_deposit(&deps.storage).unwrap(),
+        vec![coin(8, "untrn")]
+    );
+}
+
+#[test]
+fn test_query_registry_undispatched_registration() {
+    let registry = QueryRegistry::new(
+        "queries",
+        "queries_labels",
+        "queries_pending",
+        REGISTER_QUERY_REPLY_ID,
+    );
+    let mut deps = mock_dependencies_with_deposits(&[(4, b"delegations"), (6, b"balance")]);
+
+    // the submessage of the first registration is never dispatched
+    registry
+        .register(
+            deps.as_mut().storage,
+            "balance",
+            register_kv_query_msg(b"balance"),
+        )
+        .unwrap();
+    registry
+        .register(
+            deps.as_mut().storage,
+            "delegations",
+            register_kv_query_msg(b"delegations"),
+        )
+        .unwrap();
+
+    let delegations_query = registry
+        .handle_reply(deps.as_mut(), register_query_reply(4))
+        .unwrap();
+    assert_eq!(delegations_query.label, "delegations");
+    assert_eq!(
+        delegations_query.keys,
+        vec![KVKey {
+            path: "bank".to_string(),
+            key: Binary::from(b"delegations".as_slice()),
+        }]
+    );
+    assert_eq!(registry.query_id(&deps.storage, "balance").unwrap(), None);
+
+    // the label of the undispatched registration is reserved until pending registrations are cleared
+    assert_eq!(
+        registry
+            .register(
+                deps.as_mut().storage,
+                "balance",
+                register_kv_query_msg(b"balance")
+            )
+            .unwrap_err(),
+        NeutronError::QueryLabelAlreadyExists {
+            label: "balance".to_string()
+        }
+    );
+    registry.clear_pending(deps.as_mut().storage).unwrap();
+    assert!(registry
+        .handle_reply(deps.as_mut(), register_query_reply(6))
+        .is_err());
+
+    registry
+        .register(
+            deps.as_mut().storage,
+            "balance",
+            register_kv_query_msg(b"balance"),
+        )
+        .unwrap();
+    let balance_query = registry
+        .handle_reply(deps.as_mut(), register_query_reply(6))
+        .unwrap();
+    assert_eq!(balance_query.label, "balance");
+    assert_eq!(
+        registry.range(&deps.storage, None, None).unwrap(),
+        vec![delegations_query, balance_query]
+    );
+}
+
+#[test]
+fn test_query_registry_update_and_remove() {
+    let registry = QueryRegistry::new(
+        "queries",
+        "queries_labels",
+        "queries_pending",
+        REGISTER_QUERY_REPLY_ID,
+    );
+    let mut deps = mock_dependencies_with_deposits(&[(2, b"balance")]);
+
+    registry
+        .register(
+            deps.as_mut().storage,
+            "balance",
+            register_kv_query_msg(b"balance"),
+        )
+        .unwrap();
+    registry
+        .handle_reply(deps.as_mut(), register_query_reply(2))
+        .unwrap();
+
+    let msg = registry
+        .update(deps.as_mut().storage, 2, None, Some(20), None)
+        .unwrap();
+    assert_eq!(
+        msg,
+        NeutronMsg::update_interchain_query(2, None, Some(20), None).unwrap()
+    );
+    let query = registry.load(&deps.storage, 2).unwrap();
+    assert_eq!(query.update_period, 20);
+    assert_eq!(query.keys.len(), 1);
+    assert!(registry
+        .update(deps.as_mut().storage, 3, None, Some(20), None)
+        .is_err());
+
+    let msg = registry
+        .remove_by_label(deps.as_mut().storage, "balance")
+        .unwrap();
+    assert_eq!(msg, NeutronMsg::remove_interchain_query(2));
+    assert_eq!(registry.may_load(&deps.storage, 2).unwrap(), None);
+    assert_eq!(registry.query_id(&deps.storage, "balance").unwrap(), None);
+    assert!(registry.remove(deps.as_mut().storage, 2).is_err());
+    assert_eq!(registry.total_deposit(&deps.storage).unwrap(), vec![]);
+
+    // the label can be used again after removal
+    registry
+        .register(
+            deps.as_mut().storage,
+            "balance",
+            register_kv_query_msg(b"balance"),
+        )
+        .unwrap();
+}
+
+fn build_query_result(revision: u64, height: u64) -> InterchainQueryResult {
+    InterchainQueryResult {
+        kv_results: vec![StorageValue {
+            storage_prefix: "bank".to_string(),
+            key: Binary::from(b"key".as_slice()),
+            value: Binary::from(b"value".as_slice()),
+        }],
+        height,
+        revision,
+    }
+}
+
+#[test]
+fn test_query_result_freshness_check() {
+    struct TestCase {
+        freshness: QueryResultFreshness,
+        result: InterchainQueryResult,
+        local_height: u64,
+        current_height: u64,
+        expected_reason: Option<&'static str>,
+    }
+    let test_cases = vec![
+        TestCase {
+            freshness: QueryResultFreshness::new(),
+            result: build_query_result(0, 0),
+            local_height: 0,
+            current_height: 1000,
+            expected_reason: None,
+        },
+        TestCase {
+            freshness: QueryResultFreshness::new().with_min_remote_height(Height {
+                revision_number: 1,
+                revision_height: 100,
+            }),
+            result: build_query_result(1, 100),
+            local_height: 10,
+            current_height: 1000,
+            expected_reason: None,
+        },
+        TestCase {
+            freshness: QueryResultFreshness::new().with_min_remote_height(Height {
+                revision_number: 1,
+                revision_height: 100,
+            }),
+            result: build_query_result(1, 99),
+            local_height: 10,
+            current_height: 1000,
+            expected_reason: Some("remote height 1-99 is lower than 1-100"),
+        },
+        TestCase {
+            // a result from a newer revision is always higher
+            freshness: QueryResultFreshness::new().with_min_remote_height(Height {
+                revision_number: 1,
+                revision_height: 100,
+            }),
+            result: build_query_result(2, 1),
+            local_height: 10,
+            current_height: 1000,
+            expected_reason: None,
+        },
+        TestCase {
+            freshness: QueryResultFreshness::new().with_min_remote_height(Height {
+                revision_number: 1,
+                revision_height: 100,
+            }),
+            result: build_query_result(0, 1000),
+            local_height: 10,
+            current_height: 1000,
+            expected_reason: Some("remote height 0-1000 is lower than 1-100"),
+        },
+        TestCase {
+            freshness: QueryResultFreshness::new().with_max_local_age(10),
+            result: build_query_result(0, 100),
+            local_height: 990,
+            current_height: 1000,
+            expected_reason: None,
+        },
+        TestCase {
+            freshness: QueryResultFreshness::new().with_max_local_age(10),
+            result: build_query_result(0, 100),
+            local_height: 989,
+            current_height: 1000,
+            expected_reason: Some("result was submitted 11 blocks ago, max allowed age is 10"),
+        },
+        TestCase {
+            freshness: QueryResultFreshness::new().with_max_local_age(10),
+            result: build_query_result(0, 0),
+            local_height: 0,
+            current_height: 5,
+            expected_reason: Some("result has never been submitted"),
+        },
+    ];
+
+    for tc in test_cases {
+        let res = tc
+            .freshness
+            .check(1, &tc.result, tc.local_height, tc.current_height);
+        match tc.expected_reason {
+            None => res.unwrap(),
+            Some(reason) => assert_eq!(
+                res.unwrap_err(),
+                NeutronError::StaleQueryResult {
+                    query_id: 1,
+                    remote_height: tc.result.height,
+                    revision: tc.result.revision,
+                    local_height: tc.local_height,
+                    reason: reason.to_string(),
+                }
+            ),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct Value(Binary);
+
+impl KVReconstruct for Value {
+    fn reconstruct(kvs: &[StorageValue]) -> NeutronResult<Self> {
+        Ok(Value(kvs[0].value.clone()))
+    }
+}
+
+fn mock_dependencies_with_query_result(
+) -> OwnedDeps<MockStorage, MockApi, MockQuerier<NeutronQuery>, NeutronQuery> {
+    let querier =
+        MockQuerier::<NeutronQuery>::new(&[]).with_custom_handler(move |query| match query {
+            NeutronQuery::RegisteredInterchainQuery { query_id } => {
+                let mut registered_query = build_registered_query(*query_id);
+                registered_query.last_submitted_result_local_height = 12_000;
+                SystemResult::Ok(ContractResult::Ok(
+                    to_binary(&QueryRegisteredQueryResponse { registered_query }).unwrap(),
+                ))
+            }
+            NeutronQuery::InterchainQueryResult { .. } => SystemResult::Ok(ContractResult::Ok(
+                to_binary(&QueryRegisteredQueryResultResponse {
+                    result: build_query_result(2, 100),
+                })
+                .unwrap(),
+            )),
+            _ => unimplemented!(),
+        });
+
+    OwnedDeps {
+        storage: MockStorage::default(),
+        api: MockApi::default(),
+        querier,
+        custom_query_type: PhantomData,
+    }
+}
+
+#[test]
+fn test_query_fresh_kv_result() {
+    let deps = mock_dependencies_with_query_result();
+    // mock_env() is at height 12_345
+    let env = mock_env();
+
+    let value: Value = query_fresh_kv_result(
+        deps.as_ref(),
+        &env,
+        1,
+        &QueryResultFreshness::new().with_max_local_age(345),
+    )
+    .unwrap();
+    assert_eq!(value.0, Binary::from(b"value".as_slice()));
+
+    let err = query_fresh_kv_result::<Value>(
+        deps.as_ref(),
+        &env,
+        1,
+        &QueryResultFreshness::new().with_max_local_age(344),
+    )
+    .err()
+    .unwrap();
+    assert!(matches!(err, NeutronError::StaleQueryResult { .. }));
+}
+
+#[test]
+fn test_query_kv_result_with_meta() {
+    let deps = mock_dependencies_with_query_result();
+
+    let result: QueryResultWithMeta<Value> = query_kv_result_with_meta(deps.as_ref(), 1).unwrap();
+    assert_eq!(
+        result,
+        QueryResultWithMeta {
+            result: Value(Binary::from(b"value".as_slice())),
+            remote_height: 100,
+            revision: 2,
+            local_height: 12_000,
+            connection_id: "connection-0".to_string(),
+        }
+    );
+}
+
+#[test]
+fn test_composite_query_builder() {
+    let addr = "osmo1yz54ncxj9csp7un3xled03q6thrrhy9cztkfzs".to_string();
+    let balance_msg = new_register_balance_query_msg(
+        "connection-0".to_string(),
+        addr.clone(),
+        "uosmo".to_string(),
+        1,
+    )
+    .unwrap();
+    let validators_msg = new_register_staking_validators_query_msg(
+        "connection-0".to_string(),
+        vec![
+            "osmovaloper1r2u5q6t6w0wssrk6l66n3t2q3dw2uqny4gj2e3".to_string(),
+            "osmovaloper1lzhlnpahvznwfv4jmay2tgaha5kmz5qxwmj9we".to_string(),
+        ],
+        2,
+    )
+    .unwrap();
+
+    let builder = CompositeQueryBuilder::new()
+        .with_query(balance_msg.clone())
+        .unwrap()
+        .with_query(validators_msg.clone())
+        .unwrap();
+    assert_eq!(builder.key_counts(), vec![1, 2]);
+
+    let keys = |msg: &NeutronMsg| match msg {
+        NeutronMsg::RegisterInterchainQuery { keys, .. } => keys.clone(),
+        _ => unreachable!(),
+    };
+    assert_eq!(
+        builder.build(10).unwrap(),
+        NeutronMsg::register_interchain_query(
+            QueryPayload::KV([keys(&balance_msg), keys(&validators_msg)].concat()),
+            "connection-0".to_string(),
+            10,
+        )
+        .unwrap()
+    );
+
+    // all the queries must be KV queries for the same connection
+    assert!(CompositeQueryBuilder::new()
+        .with_query(balance_msg.clone())
+        .unwrap()
+        .with_query(
+            new_register_balance_query_msg(
+                "connection-1".to_string(),
+                addr.clone(),
+                "uosmo".to_string(),
+                1
+            )
+            .unwrap()
+        )
+        .is_err());
+    assert_eq!(
+        CompositeQueryBuilder::new()
+            .with_query(
+                new_register_transfers_query_msg("connection-0".to_string(), addr, 1, None)
+                    .unwrap()
+            )
+            .unwrap_err(),
+        NeutronError::InvalidQueryType {
+            query_type: "tx".to_string()
+        }
+    );
+    assert!(CompositeQueryBuilder::new()
+        .with_query(NeutronMsg::remove_interchain_query(1))
+        .is_err());
+    assert!(CompositeQueryBuilder::new().build(10).is_err());
+}
+
+#[test]
+fn test_composite_reconstruct() {
+    let balance = |denom: &str, amount: &str| StorageValue {
+        storage_prefix: "bank".to_string(),
+        key: Binary::default(),
+        value: Binary(
+            cosmos_sdk_proto::cosmos::base::v1beta1::Coin {
+                denom: denom.to_string(),
+                amount: amount.to_string(),
+            }
+            .encode_to_vec(),
+        ),
+    };
+    let absent = StorageValue {
+        storage_prefix: "bank".to_string(),
+        key: Binary::default(),
+        value: Binary::default(),
+    };
+    let kvs = vec![
+        balance("uosmo", "100"),
+        balance("uatom", "200"),
+        absent,
+        balance("untrn", "300"),
+    ];
+
+    let (balances, optional): (Balances, Vec<Option<Coin>>) =
+        KVReconstructComposite::reconstruct_composite(&kvs, &[2, 2]).unwrap();
+    assert_eq!(
+        balances,
+        Balances {
+            coins: vec![coin(100, "uosmo"), coin(200, "uatom")]
+        }
+    );
+    assert_eq!(optional, vec![None, Some(coin(300, "untrn"))]);
+
+    let (first, second, third): (Balances, Balances, Vec<Option<Coin>>) =
+        KVReconstructComposite::reconstruct_composite(&kvs, &[1, 0, 3]).unwrap();
+    assert_eq!(first.coins, vec![coin(100, "uosmo")]);
+    assert_eq!(second.coins, vec![]);
+    assert_eq!(third.len(), 3);
+
+    // amount of values must match key counts
+    assert_eq!(
+        <(Balances, Balances)>::reconstruct_composite(&kvs, &[2, 1]).unwrap_err(),
+        NeutronError::InvalidQueryResultFormat("expected 3 storage values, got 4".to_string())
+    );
+    assert_eq!(
+        <(Balances, Balances)>::reconstruct_composite(&kvs, &[2, 1, 1]).unwrap_err(),
+        NeutronError::InvalidQueryResultFormat("expected 2 key counts, got 3".to_string())
+    );
+}
+
+/// ICS-23 proof verification is only available with the `proof` feature
+#[cfg(feature = "proof")]
+mod proof {
+    use super::*;
+    use crate::interchain_queries::proof::{
+        verify_storage_value, CommitmentProof, Sha2HostFunctions,
+    };
+    use ics23::commitment_proof::Proof;
+    use ics23::{ExistenceProof, HashOp, InnerOp, LeafOp, LengthOp, NonExistenceProof};
+    use sha2::{Digest, Sha256};
+
+    fn sha256(data: &[u8]) -> Vec<u8> {
+        Sha256::digest(data).to_vec()
+    }
+
+    fn length_prefixed(data: &[u8]) -> Vec<u8> {
+        let mut res = vec![];
+        cosmos_sdk_proto::prost::encoding::encode_varint(data.len() as u64, &mut res);
+        res.extend_from_slice(data);
+        res
+    }
+
+    /// Builds a leaf of **spec** style: IAVL leaves have height, size and version in the prefix
+    fn build_leaf(prefix: Vec<u8>, key: &[u8], value: &[u8]) -> (LeafOp, Vec<u8>) {
+        let leaf = LeafOp {
+            hash: HashOp::Sha256.into(),
+            prehash_key: HashOp::NoHash.into(),
+            prehash_value: HashOp::Sha256.into(),
+            length: LengthOp::VarProto.into(),
+            prefix,
+        };
+        let hash = sha256(
+            &[
+                leaf.prefix.clone(),
+                length_prefixed(key),
+                length_prefixed(&sha256(value)),
+            ]
+            .concat(),
+        );
+        (leaf, hash)
+    }
+
+    /// Builds inner ops of both children of a node with **prefix** and returns the node hash
+    fn build_inner(
+        prefix: Vec<u8>,
+        child_prefix: Vec<u8>,
+        left: &[u8],
+        right: &[u8],
+    ) -> (InnerOp, InnerOp, Vec<u8>) {
+        let left_op = InnerOp {
+            hash: HashOp::Sha256.into(),
+            prefix: [prefix.clone(), child_prefix.clone()].concat(),
+            suffix: [child_prefix.clone(), right.to_vec()].concat(),
+        };
+        let right_op = InnerOp {
+            hash: HashOp::Sha256.into(),
+            prefix: [prefix, child_prefix.clone(), left.to_vec(), child_prefix].concat(),
+            suffix: vec![],
+        };
+        let hash = sha256(
+            &[
+                left_op.prefix.clone(),
+                left.to_vec(),
+                left_op.suffix.clone(),
+            ]
+            .concat(),
+        );
+        (left_op, right_op, hash)
+    }
+
+    struct ProvenStore {
+        app_hash: Vec<u8>,
+        /// IAVL existence proofs of the "a" and "c" keys
+        key_a: ExistenceProof,
+        key_c: ExistenceProof,
+        /// multistore proof of the "bank" store
+        bank_store: CommitmentProof,
+    }
+
+    /// Builds a multistore with "bank" and "staking" stores,
+    /// the "bank" store contains "a" => "1" and "c" => "3" items
+    fn build_proven_store() -> ProvenStore {
+        // IAVL leaf prefix: height 0, size 1, version 1 (zigzag varints)
+        let (leaf_a, hash_a) = build_leaf(vec![0, 2, 2], b"a", b"1");
+        let (leaf_c, hash_c) = build_leaf(vec![0, 2, 2], b"c", b"3");
+        // IAVL inner prefix: height 1, size 2, version 1, children are length prefixed
+        let (inner_a, inner_c, bank_root) = build_inner(vec![2, 4, 2], vec![32], &hash_a, &hash_c);
+
+        let (bank_leaf, bank_hash) = build_leaf(vec![0], b"bank", &bank_root);
+        let (_, staking_hash) = build_leaf(vec![0], b"staking", b"staking root");
+        let (bank_inner, _, app_hash) = build_inner(vec![1], vec![], &bank_hash, &staking_hash);
+
+        ProvenStore {
+            app_hash,
+            key_a: ExistenceProof {
+                key: b"a".to_vec(),
+                value: b"1".to_vec(),
+                leaf: Some(leaf_a),
+                path: vec![inner_a],
+            },
+            key_c: ExistenceProof {
+                key: b"c".to_vec(),
+                value: b"3".to_vec(),
+                leaf: Some(leaf_c),
+                path: vec![inner_c],
+            },
+            bank_store: CommitmentProof {
+                proof: Some(Proof::Exist(ExistenceProof {
+                    key: b"bank".to_vec(),
+                    value: bank_root,
+                    leaf: Some(bank_leaf),
+                    path: vec![bank_inner],
+                })),
+            },
+        }
+    }
+
+    fn bank_value(key: &[u8], value: &[u8]) -> StorageValue {
+        StorageValue {
+            storage_prefix: "bank".to_string(),
+            key: Binary::from(key),
+            value: Binary::from(value),
+        }
+    }
+
+    #[test]
+    fn test_verify_storage_value() {
+        let store = build_proven_store();
+        let exist = |proof: &ExistenceProof| CommitmentProof {
+            proof: Some(Proof::Exist(proof.clone())),
+        };
+        let key_a_proofs = vec![exist(&store.key_a), store.bank_store.clone()];
+
+        verify_storage_value(&store.app_hash, &bank_value(b"a", b"1"), &key_a_proofs).unwrap();
+        verify_storage_value(
+            &store.app_hash,
+            &bank_value(b"c", b"3"),
+            &[exist(&store.key_c), store.bank_store.clone()],
+        )
+        .unwrap();
+
+        // absent keys are proven with non-existence proofs
+        let key_b_proofs = vec![
+            CommitmentProof {
+                proof: Some(Proof::Nonexist(NonExistenceProof {
+                    key: b"b".to_vec(),
+                    left: Some(store.key_a.clone()),
+                    right: Some(store.key_c.clone()),
+                })),
+            },
+            store.bank_store.clone(),
+        ];
+        verify_storage_value(&store.app_hash, &bank_value(b"b", b""), &key_b_proofs).unwrap();
+
+        for (app_hash, storage_value, proofs) in [
+            // wrong value
+            (&store.app_hash, bank_value(b"a", b"2"), &key_a_proofs),
+            // a present key is not absent
+            (&store.app_hash, bank_value(b"a", b""), &key_a_proofs),
+            // an absent key has no value
+            (&store.app_hash, bank_value(b"b", b"2"), &key_b_proofs),
+            // wrong app hash
+            (&sha256(b"app hash"), bank_value(b"a", b"1"), &key_a_proofs),
+        ] {
+            assert!(matches!(
+                verify_storage_value(app_hash, &storage_value, proofs),
+                Err(NeutronError::InvalidProof(_))
+            ));
+        }
+
+        // wrong store
+        let mut staking_value = bank_value(b"a", b"1");
+        staking_value.storage_prefix = "staking".to_string();
+        assert!(verify_storage_value(&store.app_hash, &staking_value, &key_a_proofs).is_err());
+
+        assert_eq!(
+            verify_storage_value(&store.app_hash, &bank_value(b"a", b"1"), &key_a_proofs[..1])
+                .unwrap_err(),
+            NeutronError::InvalidProof("expected 2 proofs, got 1".to_string())
+        );
+
+        // proofs with unsupported hash operations are rejected instead of reaching unsupported host functions
+        let mut keccak_proof = store.key_a.clone();
+        keccak_proof.path[0].hash = HashOp::Keccak256.into();
+        let mut blake_proof = store.key_c.clone();
+        blake_proof.leaf.as_mut().unwrap().prehash_value = HashOp::Blake2b512.into();
+        let mut ripemd_store = store.bank_store.clone();
+        if let Some(Proof::Exist(proof)) = &mut ripemd_store.proof {
+            proof.leaf.as_mut().unwrap().hash = HashOp::Ripemd160.into();
+        }
+        let non_exist = |left: &ExistenceProof, right: &ExistenceProof| CommitmentProof {
+            proof: Some(Proof::Nonexist(NonExistenceProof {
+                key: b"b".to_vec(),
+                left: Some(left.clone()),
+                right: Some(right.clone()),
+            })),
+        };
+        for (storage_value, proofs) in [
+            (
+                bank_value(b"a", b"1"),
+                [exist(&keccak_proof), store.bank_store.clone()],
+            ),
+            (
+                bank_value(b"b", b""),
+                [
+                    non_exist(&keccak_proof, &store.key_c),
+                    store.bank_store.clone(),
+                ],
+            ),
+            (
+                bank_value(b"b", b""),
+                [
+                    non_exist(&store.key_a, &blake_proof),
+                    store.bank_store.clone(),
+                ],
+            ),
+            (
+                bank_value(b"a", b"1"),
+                [exist(&store.key_a), ripemd_store.clone()],
+            ),
+        ] {
+            assert_eq!(
+                verify_storage_value(&store.app_hash, &storage_value, &proofs).unwrap_err(),
+                NeutronError::InvalidProof("only sha256 hash operations are supported".to_string())
+            );
+        }
+
+        // unsupported host functions used directly don't abort, they just never produce a valid root
+        for proof in [&keccak_proof, &blake_proof] {
+            let root = ics23::calculate_existence_root::<Sha2HostFunctions>(proof).unwrap();
+            assert_ne!(
+                root,
+                ics23::calculate_existence_root::<Sha2HostFunctions>(&store.key_a).unwrap()
+            );
+        }
+        if let Some(Proof::Exist(proof)) = &ripemd_store.proof {
+            assert!(!ics23::verify_membership::<Sha2HostFunctions>(
+                &ripemd_store,
+                &ics23::tendermint_spec(),
+                &store.app_hash,
+                b"bank",
+                &proof.value,
+            ));
+        }
+    }
+}
+
+#[test]
+fn test_transaction_filter_value_serde() {
+    struct TestCase {
+        value: TransactionFilterValue,
+        json: &'static str,
+    }
+    let test_cases = vec![
+        TestCase {
+            value: TransactionFilterValue::String("recipient".to_string()),
+            json: r#""recipient""#,
+        },
+        TestCase {
+            value: TransactionFilterValue::Int(100),
+            json: "100",
+        },
+        TestCase {
+            value: TransactionFilterValue::Int(-100),
+            json: "-100",
+        },
+        TestCase {
+            value: TransactionFilterValue::BigInt(Uint128::new(u64::MAX as u128)),
+            json: "18446744073709551615",
+        },
+        TestCase {
+            value: TransactionFilterValue::BigInt(Uint128::MAX),
+            json: "340282366920938463463374607431768211455",
+        },
+    ];
+
+    for tc in test_cases {
+        assert_eq!(serde_json_wasm::to_string(&tc.value).unwrap(), tc.json);
+    }
+
+    // integers are deserialized into the smallest fitting variant
+    assert_eq!(
+        serde_json_wasm::from_str::<TransactionFilterValue>("100").unwrap(),
+        TransactionFilterValue::Int(100)
+    );
+    assert_eq!(
+        serde_json_wasm::from_str::<TransactionFilterValue>("-100").unwrap(),
+        TransactionFilterValue::Int(-100)
+    );
+    assert_eq!(
+        serde_json_wasm::from_str::<TransactionFilterValue>("18446744073709551615").unwrap(),
+        TransactionFilterValue::BigInt(Uint128::new(u64::MAX as u128))
+    );
+    assert_eq!(
+        serde_json_wasm::from_str::<TransactionFilterValue>(r#""100""#).unwrap(),
+        TransactionFilterValue::String("100".to_string())
+    );
+    assert!(serde_json_wasm::from_str::<TransactionFilterValue>("1.5").is_err());
+    // serde-json-wasm parses only 64-bit integers
+    assert!(serde_json_wasm::from_str::<TransactionFilterValue>("18446744073709551616").is_err());
+    assert!(serde_json_wasm::from_str::<TransactionFilterValue>("true").is_err());
+
+    assert_eq!(
+        TransactionFilterValue::from(u64::MAX),
+        TransactionFilterValue::BigInt(Uint128::new(u64::MAX as u128))
+    );
+    assert_eq!(
+        TransactionFilterValue::from(Uint128::new(100)),
+        TransactionFilterValue::Int(100)
+    );
+    assert_eq!(TransactionFilterValue::Int(-1).as_u64(), None);
+    assert_eq!(TransactionFilterValue::Int(1).as_u64(), Some(1));
+
+    let item = TransactionFilterItem {
+        field: "transfer.amount".to_string(),
+        op: TransactionFilterOp::Gte,
+        value: TransactionFilterValue::Int(-5),
+    };
+    assert_eq!(
+        serde_json_wasm::to_string(&item).unwrap(),
+        r#"{"field":"transfer.amount","op":"Gte","value":-5}"#
+    );
+}
+
+#[test]
+fn test_update_interchain_query() {
+    let recipient_filter = |field: &str| TransactionFilterItem {
+        field: field.to_string(),
+        op: TransactionFilterOp::Eq,
+        value: TransactionFilterValue::String("recipient".to_string()),
+    };
+    let update = |new_keys: Option<Vec<KVKey>>,
+                  new_update_period: Option<u64>,
+                  new_transactions_filter: Option<Vec<TransactionFilterItem>>| {
+        UpdateInterchainQuery {
+            query_id: 1,
+            new_keys,
+            new_update_period,
+            new_transactions_filter,
+        }
+    };
+
+    struct TestCase {
+        update: UpdateInterchainQuery,
+        expected_result: NeutronResult<()>,
+    }
+    let test_cases = vec![
+        TestCase {
+            update: update(None, Some(10), None),
+            expected_result: Ok(()),
+        },
+        TestCase {
+            update: update(
+                None,
+                None,
+                Some(vec![recipient_filter("transfer.recipient")]),
+            ),
+            expected_result: Ok(()),
+        },
+        TestCase {
+            update: update(None, None, None),
+            expected_result: Err(NeutronError::Std(StdError::generic_err(
+                "one of new_keys, new_update_period or new_transactions_filter must be set",
+            ))),
+        },
+        TestCase {
+            update: update(
+                Some(vec![KVKey {
+                    path: "bank".to_string(),
+                    key: Binary::from(vec![1u8]),
+                }]),
+                None,
+                Some(vec![]),
+            ),
+            expected_result: Err(NeutronError::Std(StdError::generic_err(
+                "new_keys and new_transactions_filter can't be set together",
+            ))),
+        },
+        TestCase {
+            update: update(Some(vec![]), None, None),
+            expected_result: Err(NeutronError::Std(StdError::generic_err(
+                "new_keys must not be empty",
+            ))),
+        },
+        TestCase {
+            update: update(None, Some(0), None),
+            expected_result: Err(NeutronError::Std(StdError::generic_err(
+                "new_update_period must be positive",
+            ))),
+        },
+        TestCase {
+            update: update(None, None, Some(vec![recipient_filter("")])),
+            expected_result: Err(NeutronError::InvalidTransactionFilter(
+                "field must not be empty".to_string(),
+            )),
+        },
+        TestCase {
+            update: update(
+                None,
+                None,
+                Some(vec![recipient_filter(
+                    "transfer.recipient='a' OR tx.height",
+                )]),
+            ),
+            expected_result: Err(NeutronError::InvalidTransactionFilter(
+                "field \"transfer.recipient='a' OR tx.height\" contains forbidden characters"
+                    .to_string(),
+            )),
+        },
+        TestCase {
+            update: update(
+                None,
+                None,
+                Some(vec![
+                    recipient_filter("transfer.recipient");
+                    MAX_TX_FILTERS + 1
+                ]),
+            ),
+            expected_result: Err(NeutronError::TooManyTransactionFilters {
+                max: MAX_TX_FILTERS,
+            }),
+        },
+    ];
+
+    for tc in test_cases {
+        assert_eq!(tc.update.validate(), tc.expected_result);
+    }
+
+    let filters = vec![recipient_filter("transfer.recipient")];
+    assert_eq!(
+        update(None, Some(5), Some(filters.clone()))
+            .into_msg()
+            .unwrap(),
+        NeutronMsg::update_interchain_query(1, None, Some(5), Some(filters)).unwrap()
+    );
+    assert!(update(None, None, None).into_msg().is_err());
+
+    // the update is deserialized from contract messages with typed filters
+    let update: UpdateInterchainQuery = serde_json_wasm::from_str(
+        r#"{"query_id":1,"new_transactions_filter":[{"field":"tx.height","op":"Gte","value":100}]}"#,
+    )
+    .unwrap();
+    assert_eq!(
+        update.new_transactions_filter,
+        Some(vec![TransactionFilterItem {
+            field: "tx.height".to_string(),
+            op: TransactionFilterOp::Gte,
+            value: TransactionFilterValue::Int(100),
+        }])
+    );
+}
+
+#[test]
+fn test_query_payload_serde() {
+    let payload = QueryPayload::TX(vec![TransactionFilterItem {
+        field: "tx.height".to_string(),
+        op: TransactionFilterOp::Gte,
+        value: TransactionFilterValue::Int(100),
+    }]);
+    let json = serde_json_wasm::to_string(&payload).unwrap();
+    assert_eq!(
+        json,
+        r#"{"tx":[{"field":"tx.height","op":"Gte","value":100}]}"#
+    );
+    assert_eq!(
+        serde_json_wasm::from_str::<QueryPayload>(&json).unwrap(),
+        payload
+    );
+}
+
+#[test]
+fn test_query_deposit() {
+    let querier = MockQuerier::<NeutronQuery>::new(&[(
+        "contract",
+        &[coin(2_000_000, "untrn"), coin(5, "uatom")],
+    )])
+    .with_custom_handler(|query| match query {
+        NeutronQuery::InterchainQueriesParams {} => SystemResult::Ok(ContractResult::Ok(
+            Binary::from(
+                br#"{"params":{"query_submit_timeout":1036800,"query_deposit":[{"denom":"untrn","amount":"1000000"}],"tx_query_removal_limit":10000}}"#
+                    .as_slice(),
+            ),
+        )),
+        _ => unimplemented!(),
+    });
+    let deps = OwnedDeps {
+        storage: MockStorage::default(),
+        api: MockApi::default(),
+        querier,
+        custom_query_type: PhantomData,
+    };
+
+    assert_eq!(
+        query_interchain_queries_params(deps.as_ref())
+            .unwrap()
+            .params,
+        InterchainQueriesParams {
+            query_submit_timeout: 1036800,
+            query_deposit: vec![coin(1_000_000, "untrn")],
+            tx_query_removal_limit: 10000,
+        }
+    );
+    assert_eq!(
+        query_deposit(deps.as_ref(), 3).unwrap(),
+        vec![coin(3_000_000, "untrn")]
+    );
+
+    struct TestCase {
+        address: &'static str,
+        queries_count: u64,
+        expected: NeutronResult<Vec<Coin>>,
+    }
+    let test_cases = vec![
+        TestCase {
+            address: "contract",
+            queries_count: 1,
+            expected: Ok(vec![coin(1_000_000, "untrn")]),
+        },
+        TestCase {
+            address: "contract",
+            queries_count: 2,
+            expected: Ok(vec![coin(2_000_000, "untrn")]),
+        },
+        TestCase {
+            address: "contract",
+            queries_count: 0,
+            expected: Ok(vec![coin(0, "untrn")]),
+        },
+        TestCase {
+            address: "contract",
+            queries_count: 3,
+            expected: Err(NeutronError::InsufficientQueryDeposit {
+                required: coin(3_000_000, "untrn"),
+                available: coin(2_000_000, "untrn"),
+            }),
+        },
+        TestCase {
+            address: "other",
+            queries_count: 1,
+            expected: Err(NeutronError::InsufficientQueryDeposit {
+                required: coin(1_000_000, "untrn"),
+                available: coin(0, "untrn"),
+            }),
+        },
+    ];
+
+    for tc in test_cases {
+        assert_eq!(
+            check_query_deposit(deps.as_ref(), tc.address, tc.queries_count),
+            tc.expected
+        );
+    }
+}
+
+#[test]
+fn test_transaction_filter_int_precision() {
+    let filter = |value: TransactionFilterValue| TransactionFilterItem {
+        field: "transfer.amount".to_string(),
+        op: TransactionFilterOp::Eq,
+        value,
+    };
+    let max = MAX_TX_FILTER_INT as i64;
+
+    for value in [
+        TransactionFilterValue::Int(max),
+        TransactionFilterValue::Int(-max),
+        TransactionFilterValue::BigInt(Uint128::new(max as u128)),
+    ] {
+        assert_eq!(validate_transactions_filter(&[filter(value)]), Ok(()));
+    }
+
+    for value in [
+        TransactionFilterValue::Int(max + 1),
+        TransactionFilterValue::Int(-max - 1),
+        TransactionFilterValue::Int(i64::MIN),
+        TransactionFilterValue::from(u64::MAX),
+        TransactionFilterValue::BigInt(Uint128::MAX),
+    ] {
+        assert_eq!(
+            validate_transactions_filter(&[filter(value)]),
+            Err(NeutronError::InvalidTransactionFilter(format!(
+                "value of field \"transfer.amount\" is out of [-{max}, {max}] range",
+                max = MAX_TX_FILTER_INT
+            )))
+        );
+    }
+}
+
+#[test]
+fn test_transaction_filter_value_int_boundaries() {
+    use serde::de::value::{Error, I128Deserializer, U128Deserializer};
+    use serde::Deserialize;
+
+    let big = |v: u128| TransactionFilterValue::BigInt(Uint128::new(v));
+    let from_u128 =
+        |v: u128| TransactionFilterValue::deserialize(U128Deserializer::<Error>::new(v)).unwrap();
+    let from_i128 =
+        |v: i128| TransactionFilterValue::deserialize(I128Deserializer::<Error>::new(v));
+
+    struct TestCase {
+        value: TransactionFilterValue,
+        json: Option<&'static str>,
+        expected: TransactionFilterValue,
+    }
+    let test_cases = vec![
+        TestCase {
+            value: TransactionFilterValue::from(i64::MIN),
+            json: Some("-9223372036854775808"),
+            expected: TransactionFilterValue::Int(i64::MIN),
+        },
+        TestCase {
+            value: from_u128(0),
+            json: Some("0"),
+            expected: TransactionFilterValue::Int(0),
+        },
+        TestCase {
+            value: from_u128(i64::MAX as u128),
+            json: Some("9223372036854775807"),
+            expected: TransactionFilterValue::Int(i64::MAX),
+        },
+        TestCase {
+            value: TransactionFilterValue::from(i64::MAX as u64 + 1),
+            json: Some("9223372036854775808"),
+            expected: big(i64::MAX as u128 + 1),
+        },
+        TestCase {
+            value: TransactionFilterValue::from(u64::MAX),
+            json: Some("18446744073709551615"),
+            expected: big(u64::MAX as u128),
+        },
+        TestCase {
+            value: from_u128(u64::MAX as u128 + 1),
+            json: None,
+            expected: big(u64::MAX as u128 + 1),
+        },
+        TestCase {
+            value: from_u128(u128::MAX),
+            json: None,
+            expected: big(u128::MAX),
+        },
+    ];
+
+    for tc in test_cases {
+        assert_eq!(tc.value, tc.expected);
+        // the variant is normalized, not only the value
+        assert_eq!(format!("{:?}", tc.value), format!("{:?}", tc.expected));
+        if let Some(json) = tc.json {
+            assert_eq!(serde_json_wasm::to_string(&tc.value).unwrap(), json);
+            let deserialized: TransactionFilterValue = serde_json_wasm::from_str(json).unwrap();
+            assert_eq!(format!("{:?}", deserialized), format!("{:?}", tc.expected));
+        }
+    }
+
+    assert_eq!(from_i128(-1).unwrap(), TransactionFilterValue::Int(-1));
+    assert_eq!(
+        from_i128(u64::MAX as i128 + 1).unwrap(),
+        big(u64::MAX as u128 + 1)
+    );
+    assert!(from_i128(i64::MIN as i128 - 1).is_err());
+
+    // integers are compared by value whatever the variant is
+    assert_eq!(big(100), TransactionFilterValue::Int(100));
+    assert_ne!(big(100), TransactionFilterValue::Int(-100));
+    assert_ne!(
+        TransactionFilterValue::Int(100),
+        TransactionFilterValue::String("100".to_string())
+    );
+}
@@ -1,9 +1,14 @@
 use crate::{
-    bindings::types::{KVKey, StorageValue},
+    bindings::msg::NeutronMsg,
+    bindings::types::{Height, InterchainQueryResult, KVKey, StorageValue},
     errors::error::NeutronResult,
+    NeutronError,
 };
-use schemars::{JsonSchema, _serde_json::Value};
+use cosmwasm_std::{StdError, Uint128};
+use schemars::JsonSchema;
+use serde::de::{self, Visitor};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
 
 pub const QUERY_TYPE_KV_VALUE: &str = "kv";
 pub const QUERY_TYPE_TX_VALUE: &str = "tx";
@@ -11,7 +16,7 @@ pub const QUERY_TYPE_TX_VALUE: &str = "tx";
 /// Maximum length of address
 pub const MAX_ADDR_LEN: usize = 255;
 
-#[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug, JsonSchema)]
 pub enum TransactionFilterOp {
     Eq,
     Lt,
@@ -20,10 +25,96 @@ pub enum TransactionFilterOp {
     Gte,
 }
 
-#[derive(PartialEq, Eq, Debug)]
+/// Value of a transactions filter item.
+///
+/// Numbers are serialized as JSON integers the way Neutron's filter parser expects them,
+/// e.g. `{"field": "tx.height", "op": "Gte", "value": 100}`, strings are serialized as JSON strings.
+/// Floats are not supported by the parser and are rejected on deserialization.
+///
+/// Neutron decodes numbers as `float64`, so only integers up to [MAX_TX_FILTER_INT] by absolute value
+/// are exact, [validate_transactions_filter] rejects filters with bigger values.
+///
+/// Integers are compared by value, so `Int(n)` is equal to `BigInt(n)`, though the constructors
+/// (`From` impls and deserialization) always put integers fitting into `i64` into **Int**.
+#[derive(Clone, Debug, JsonSchema)]
+#[serde(untagged)]
 pub enum TransactionFilterValue {
     String(String),
-    Int(u64),
+    Int(i64),
+    /// **BigInt** is for values out of `i64` range, e.g. token amounts of transaction events matched
+    /// locally, they can't be used in filters of registered queries
+    BigInt(#[schemars(with = "u128")] Uint128),
+}
+
+impl From<String> for TransactionFilterValue {
+    fn from(v: String) -> Self {
+        TransactionFilterValue::String(v)
+    }
+}
+
+impl From<&str> for TransactionFilterValue {
+    fn from(v: &str) -> Self {
+        TransactionFilterValue::String(v.to_string())
+    }
+}
+
+impl From<i64> for TransactionFilterValue {
+    fn from(v: i64) -> Self {
+        TransactionFilterValue::Int(v)
+    }
+}
+
+impl From<u64> for TransactionFilterValue {
+    fn from(v: u64) -> Self {
+        match i64::try_from(v) {
+            Ok(v) => TransactionFilterValue::Int(v),
+            Err(_) => TransactionFilterValue::BigInt(v.into()),
+        }
+    }
+}
+
+impl From<Uint128> for TransactionFilterValue {
+    fn from(v: Uint128) -> Self {
+        match i64::try_from(v.u128()) {
+            Ok(v) => TransactionFilterValue::Int(v),
+            Err(_) => TransactionFilterValue::BigInt(v),
+        }
+    }
+}
+
+impl PartialEq for TransactionFilterValue {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (TransactionFilterValue::String(a), TransactionFilterValue::String(b)) => a == b,
+            _ => match (self.sign_and_magnitude(), other.sign_and_magnitude()) {
+                (Some(a), Some(b)) => a == b,
+                _ => false,
+            },
+        }
+    }
+}
+
+impl Eq for TransactionFilterValue {}
+
+impl TransactionFilterValue {
+    /// Returns whether an integer value is negative along with its absolute value,
+    /// None for string values
+    pub(crate) fn sign_and_magnitude(&self) -> Option<(bool, u128)> {
+        match self {
+            TransactionFilterValue::String(_) => None,
+            TransactionFilterValue::Int(v) => Some((*v < 0, v.unsigned_abs() as u128)),
+            TransactionFilterValue::BigInt(v) => Some((false, v.u128())),
+        }
+    }
+
+    /// Returns the value as `u64` if it is a non-negative integer which fits into `u64`
+    pub fn as_u64(&self) -> Option<u64> {
+        match self {
+            TransactionFilterValue::String(_) => None,
+            TransactionFilterValue::Int(v) => u64::try_from(*v).ok(),
+            TransactionFilterValue::BigInt(v) => u64::try_from(v.u128()).ok(),
+        }
+    }
 }
 
 impl<'de> Deserialize<'de> for TransactionFilterValue {
@@ -31,19 +122,52 @@ impl<'de> Deserialize<'de> for TransactionFilterValue {
     where
         D: Deserializer<'de>,
     {
-        use serde::de::Error;
+        deserializer.deserialize_any(TransactionFilterValueVisitor)
+    }
+}
+
+struct TransactionFilterValueVisitor;
+
+impl<'de> Visitor<'de> for TransactionFilterValueVisitor {
+    type Value = TransactionFilterValue;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("an integer or a string")
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<Self::Value, E> {
+        Ok(TransactionFilterValue::Int(v))
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+        Ok(v.into())
+    }
 
-        let v = Value::deserialize(deserializer)?;
-        let n = v.as_u64();
-        if let Some(n) = n {
-            Ok(Self::Int(n))
-        } else {
-            let n = v
-                .as_str()
-                .ok_or_else(|| D::Error::custom("Value must be number or string"))?;
-            Ok(Self::String(n.to_string()))
+    fn visit_i128<E: de::Error>(self, v: i128) -> Result<Self::Value, E> {
+        match i64::try_from(v) {
+            Ok(v) => Ok(TransactionFilterValue::Int(v)),
+            Err(_) => self.visit_u128(
+                u128::try_from(v)
+                    .map_err(|_| E::custom(format!("integer {} is out of range", v)))?,
+            ),
         }
     }
+
+    fn visit_u128<E: de::Error>(self, v: u128) -> Result<Self::Value, E> {
+        Ok(Uint128::new(v).into())
+    }
+
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<Self::Value, E> {
+        Err(E::invalid_type(de::Unexpected::Float(v), &self))
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        Ok(TransactionFilterValue::String(v.to_string()))
+    }
+
+    fn visit_string<E: de::Error>(self, v: String) -> Result<Self::Value, E> {
+        Ok(TransactionFilterValue::String(v))
+    }
 }
 
 impl Serialize for TransactionFilterValue {
@@ -53,20 +177,74 @@ impl Serialize for TransactionFilterValue {
     {
         match self {
             TransactionFilterValue::String(v) => serializer.serialize_str(v),
-            TransactionFilterValue::Int(v) => serializer.serialize_u64(*v),
+            TransactionFilterValue::Int(v) => serializer.serialize_i64(*v),
+            TransactionFilterValue::BigInt(v) => match u64::try_from(v.u128()) {
+                Ok(v) => serializer.serialize_u64(v),
+                Err(_) => serializer.serialize_u128(v.u128()),
+            },
         }
     }
 }
 
 pub const MAX_TX_FILTERS: usize = 32;
 
-#[derive(Serialize, Deserialize, Debug)]
+/// Maximum absolute value of an integer in a transactions filter, Neutron decodes filter values as `float64`
+/// which can't represent bigger integers exactly
+pub const MAX_TX_FILTER_INT: u64 = 1 << 53;
+
+/// Characters Neutron does not allow in transactions filter fields
+pub const TX_FILTER_FIELD_FORBIDDEN_CHARACTERS: &str = "\t\n\r\\()\"'=><";
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 pub struct TransactionFilterItem {
     pub field: String,
     pub op: TransactionFilterOp,
     pub value: TransactionFilterValue,
 }
 
+impl TransactionFilterItem {
+    /// Checks the **field** is a valid event attribute the same way the interchainqueries module does it
+    /// and the **value** is represented exactly by the module
+    pub fn validate(&self) -> NeutronResult<()> {
+        if self.field.is_empty() {
+            return Err(NeutronError::InvalidTransactionFilter(
+                "field must not be empty".to_string(),
+            ));
+        }
+        if self
+            .field
+            .contains(|c| TX_FILTER_FIELD_FORBIDDEN_CHARACTERS.contains(c))
+        {
+            return Err(NeutronError::InvalidTransactionFilter(format!(
+                "field {:?} contains forbidden characters",
+                self.field
+            )));
+        }
+        let (_, magnitude) = self.value.sign_and_magnitude().unwrap_or_default();
+        if magnitude > MAX_TX_FILTER_INT as u128 {
+            return Err(NeutronError::InvalidTransactionFilter(format!(
+                "value of field {:?} is out of [-{max}, {max}] range",
+                self.field,
+                max = MAX_TX_FILTER_INT
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+/// Validates a transactions **filters** of a TX Interchain Query: checks the amount of the filters
+/// and every filter item, see [TransactionFilterItem::validate]
+pub fn validate_transactions_filter(filters: &[TransactionFilterItem]) -> NeutronResult<()> {
+    if filters.len() > MAX_TX_FILTERS {
+        return Err(NeutronError::TooManyTransactionFilters {
+            max: MAX_TX_FILTERS,
+        });
+    }
+
+    filters.iter().try_for_each(TransactionFilterItem::validate)
+}
+
 #[derive(Serialize, Deserialize, Copy, Clone, Debug, PartialEq, Eq, JsonSchema)]
 /// Describes possible interchain query types
 pub enum QueryType {
@@ -90,16 +268,89 @@ impl Into<String> for QueryType {
 }
 
 /// Describes possible interchain query types with a payload
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 pub enum QueryPayload {
     /// **kv** is an interchain query type to query KV values from remote chain
     /// payload is kvkeys
+    #[serde(rename = "kv")]
     KV(Vec<KVKey>),
 
     /// **tx** is an interchain query type to query transactions from remote chain
     /// payload is transactions filter
+    #[serde(rename = "tx")]
     TX(Vec<TransactionFilterItem>),
 }
 
+/// Parameters of an interchain query update, intended to be used as is in contract messages:
+/// ```rust ignore
+/// pub enum ExecuteMsg {
+///     UpdateInterchainQuery(UpdateInterchainQuery),
+/// }
+///
+/// ExecuteMsg::UpdateInterchainQuery(update) => {
+///     Ok(Response::new().add_message(update.into_msg()?))
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct UpdateInterchainQuery {
+    /// **query_id** is the ID of the query to update
+    pub query_id: u64,
+    /// **new_keys** is new keys of a KV query
+    pub new_keys: Option<Vec<KVKey>>,
+    /// **new_update_period** is a new update period (in neutron blocks) of the query
+    pub new_update_period: Option<u64>,
+    /// **new_transactions_filter** is a new transactions filter of a TX query
+    pub new_transactions_filter: Option<Vec<TransactionFilterItem>>,
+}
+
+impl UpdateInterchainQuery {
+    /// Validates the update the same way the interchainqueries module does it: something must be
+    /// updated, keys and transactions filter can't be updated together, the update period must be
+    /// positive and the transactions filter must be valid
+    pub fn validate(&self) -> NeutronResult<()> {
+        if self.new_keys.is_none()
+            && self.new_update_period.is_none()
+            && self.new_transactions_filter.is_none()
+        {
+            return Err(NeutronError::Std(StdError::generic_err(
+                "one of new_keys, new_update_period or new_transactions_filter must be set",
+            )));
+        }
+        if self.new_keys.is_some() && self.new_transactions_filter.is_some() {
+            return Err(NeutronError::Std(StdError::generic_err(
+                "new_keys and new_transactions_filter can't be set together",
+            )));
+        }
+        if self.new_keys.as_ref().map_or(false, Vec::is_empty) {
+            return Err(NeutronError::Std(StdError::generic_err(
+                "new_keys must not be empty",
+            )));
+        }
+        if self.new_update_period == Some(0) {
+            return Err(NeutronError::Std(StdError::generic_err(
+                "new_update_period must be positive",
+            )));
+        }
+        if let Some(filters) = &self.new_transactions_filter {
+            validate_transactions_filter(filters)?;
+        }
+
+        Ok(())
+    }
+
+    /// Validates the update and builds `NeutronMsg::UpdateInterchainQuery` out of it
+    pub fn into_msg(self) -> NeutronResult<NeutronMsg> {
+        self.validate()?;
+        NeutronMsg::update_interchain_query(
+            self.query_id,
+            self.new_keys,
+            self.new_update_period,
+            self.new_transactions_filter,
+        )
+    }
+}
+
 /// Bytes representations of Bech32 address
 pub type AddressBytes = Vec<u8>;
 
@@ -122,3 +373,135 @@ pub trait KVReconstruct: Sized {
     /// Reconstructs this value from the slice of **StorageValue**'s.
     fn reconstruct(kvs: &[StorageValue]) -> NeutronResult<Self>;
 }
+
+/// Derives [KVReconstruct] for a struct which fields are reconstructed from the query values one by one.
+/// See `neutron-sdk-derive` crate for details.
+#[cfg(feature = "derive")]
+pub use neutron_sdk_derive::KVReconstruct;
+
+/// A **value** that can be reconstructed from a single **StorageValue**.
+///
+/// Cosmos-SDK modules delete store entries instead of keeping empty values (e.g. after a full
+/// undelegation or when a balance drops to zero), so the result of a KV query contains an empty
+/// value for every requested key which is absent on remote chain.
+/// `Vec<Option<T>>` reconstruction maps every requested key to `Some(value)` if the key is present
+/// and to `None` otherwise, preserving the order of the query keys:
+/// ```rust ignore
+/// let validators: Vec<Option<Validator>> = query_kv_result(deps, query_id)?;
+/// ```
+pub trait KVReconstructValue: Sized {
+    /// Reconstructs this value from the present (i.e. non-empty) **StorageValue**.
+    fn reconstruct_value(kv: &StorageValue) -> NeutronResult<Self>;
+
+    /// Reconstructs this value from the **StorageValue**, returns `None` if the value is absent.
+    fn reconstruct_optional(kv: &StorageValue) -> NeutronResult<Option<Self>> {
+        if kv.value.is_empty() {
+            return Ok(None);
+        }
+        Self::reconstruct_value(kv).map(Some)
+    }
+}
+
+impl<T: KVReconstructValue> KVReconstruct for Vec<Option<T>> {
+    fn reconstruct(kvs: &[StorageValue]) -> NeutronResult<Self> {
+        kvs.iter().map(T::reconstruct_optional).collect()
+    }
+}
+
+/// Reconstructed Interchain Query result along with the heights it was obtained at,
+/// see `query_kv_result_with_meta`
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct QueryResultWithMeta<T> {
+    /// **result** is the reconstructed query result
+    pub result: T,
+    /// **remote_height** is the remote chain height the result was obtained at
+    pub remote_height: u64,
+    /// **revision** is the remote chain revision the result was obtained at
+    pub revision: u64,
+    /// **local_height** is the Neutron height the result was submitted at
+    pub local_height: u64,
+    /// **connection_id** is an IBC connection identifier between Neutron and the remote chain
+    pub connection_id: String,
+}
+
+/// Freshness bounds for an Interchain Query result, see `query_fresh_kv_result`.
+/// Unset bounds are not checked.
+#[derive(Default, Clone, Debug, PartialEq, Eq)]
+pub struct QueryResultFreshness {
+    /// **min_remote_height** is the lowest acceptable remote chain height of the result.
+    /// Heights are compared by revision first, so any result from a newer revision is acceptable.
+    pub min_remote_height: Option<Height>,
+    /// **max_local_age** is the maximum amount of Neutron blocks passed since the result submission
+    pub max_local_age: Option<u64>,
+}
+
+impl QueryResultFreshness {
+    /// Creates freshness bounds which accept any result, same as `QueryResultFreshness::default()`
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the lowest acceptable remote chain height of the result
+    pub fn with_min_remote_height(mut self, min_remote_height: Height) -> Self {
+        self.min_remote_height = Some(min_remote_height);
+        self
+    }
+
+    /// Sets the maximum amount of Neutron blocks passed since the result submission
+    pub fn with_max_local_age(mut self, max_local_age: u64) -> Self {
+        self.max_local_age = Some(max_local_age);
+        self
+    }
+
+    /// Checks the **result** of the query **query_id** submitted at Neutron height **local_height**
+    /// against the bounds at current Neutron height **current_height**.
+    /// Returns `NeutronError::StaleQueryResult` if the result is outdated.
+    pub fn check(
+        &self,
+        query_id: u64,
+        result: &InterchainQueryResult,
+        local_height: u64,
+        current_height: u64,
+    ) -> NeutronResult<()> {
+        let stale = |reason: String| NeutronError::StaleQueryResult {
+            query_id,
+            remote_height: result.height,
+            revision: result.revision,
+            local_height,
+            reason,
+        };
+
+        if let Some(min_remote_height) = &self.min_remote_height {
+            if (result.revision, result.height)
+                < (
+                    min_remote_height.revision_number,
+                    min_remote_height.revision_height,
+                )
+            {
+                return Err(stale(format!(
+                    "remote height {}-{} is lower than {}-{}",
+                    result.revision,
+                    result.height,
+                    min_remote_height.revision_number,
+                    min_remote_height.revision_height
+                )));
+            }
+        }
+
+        if let Some(max_local_age) = self.max_local_age {
+            if local_height == 0 {
+                return Err(stale("result has never been submitted".to_string()));
+            }
+            let age = current_height.saturating_sub(local_height);
+            if age > max_local_age {
+                return Err(stale(format!(
+                    "result was submitted {} blocks ago, max allowed age is {}",
+                    age, max_local_age
+                )));
+            }
+        }
+
+        Ok(())
+    }
+}
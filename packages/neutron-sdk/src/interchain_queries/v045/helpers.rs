@@ -165,6 +165,21 @@ pub fn get_total_supply_denom(denom: &Binary) -> Option<String> {
     None
 }
 
+/// Returns denom from account balance StorageValue key
+/// <https://github.com/cosmos/cosmos-sdk/blob/ad9e5620fb3445c716e9de45cfcdb56e8f1745bf/x/bank/types/key.go#L55>
+pub fn get_balance_denom(key: &Binary) -> Option<String> {
+    if key.len() < 2 || key[0] != BALANCES_PREFIX {
+        return None;
+    }
+    // storage key is followed by length prefixed address and denom
+    let denom_start = 2 + key[1] as usize;
+    if key.len() <= denom_start {
+        return None;
+    }
+
+    from_utf8(&key[denom_start..]).ok().map(|d| d.to_string())
+}
+
 /// Returns total supply amount from StorageValue key
 pub fn get_total_supply_amount(amount: &Binary) -> Option<Uint128> {
     from_utf8(amount).ok().map(|a| Uint128::from_str(a).ok())?
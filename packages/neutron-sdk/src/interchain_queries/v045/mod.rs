@@ -1,13 +1,14 @@
 pub mod helpers;
 pub mod queries;
 pub mod register_queries;
+pub mod tx;
 pub mod types;
 
 pub use register_queries::{
     new_register_balance_query_msg, new_register_bank_total_supply_query_msg,
     new_register_delegator_delegations_query_msg, new_register_distribution_fee_pool_query_msg,
     new_register_gov_proposal_query_msg, new_register_staking_validators_query_msg,
-    new_register_transfers_query_msg,
+    new_register_transfers_query_msg, new_update_tx_query_min_height_msg,
 };
 
 #[cfg(test)]
@@ -240,7 +240,7 @@ pub fn new_register_transfers_query_msg(
         query_data.push(TransactionFilterItem {
             field: HEIGHT_FIELD.to_string(),
             op: TransactionFilterOp::Gte,
-            value: TransactionFilterValue::Int(min_height),
+            value: min_height.into(),
         })
     }
 
@@ -250,3 +250,54 @@ pub fn new_register_transfers_query_msg(
         update_period,
     )
 }
+
+/// Creates a message to update transactions filter of a TX Interchain Query, so it only searches for
+/// transactions at **height** and above. Usually it is used right after processing a
+/// `SudoMsg::TxQueryResult` to not receive already processed transactions again, e.g. after the
+/// contract migration or the query re-registration.
+///
+/// * **query_id** is an identifier of the registered TX Interchain Query;
+/// * **transactions_filter** is the current filter of the query, i.e. `RegisteredQuery.transactions_filter`;
+/// * **height** is a remote chain height of the processed transaction.
+///
+/// All lower bounds of **tx.height** are replaced with a single `tx.height >= height` filter, the
+/// rest of the filters are preserved as is. Transactions at **height** itself are still searched,
+/// since one block may contain several transactions of interest.
+/// Returns `None` if the filter already has a lower bound which is not less than **height**.
+pub fn new_update_tx_query_min_height_msg(
+    query_id: u64,
+    transactions_filter: &str,
+    height: u64,
+) -> NeutronResult<Option<NeutronMsg>> {
+    let filters: Vec<TransactionFilterItem> = serde_json_wasm::from_str(transactions_filter)?;
+
+    let (lower_bounds, mut new_filters): (Vec<TransactionFilterItem>, Vec<TransactionFilterItem>) =
+        filters.into_iter().partition(|f| {
+            f.field == HEIGHT_FIELD
+                && matches!(f.op, TransactionFilterOp::Gte | TransactionFilterOp::Gt)
+        });
+
+    let up_to_date = lower_bounds
+        .iter()
+        .any(|f| match (&f.op, f.value.as_u64()) {
+            (TransactionFilterOp::Gte, Some(min_height)) => min_height >= height,
+            (TransactionFilterOp::Gt, Some(min_height)) => min_height >= height.saturating_sub(1),
+            _ => false,
+        });
+    if up_to_date {
+        return Ok(None);
+    }
+
+    new_filters.push(TransactionFilterItem {
+        field: HEIGHT_FIELD.to_string(),
+        op: TransactionFilterOp::Gte,
+        value: height.into(),
+    });
+
+    Ok(Some(NeutronMsg::update_interchain_query(
+        query_id,
+        None,
+        None,
+        Some(new_filters),
+    )?))
+}
@@ -1,26 +1,41 @@
-use crate::bindings::types::StorageValue;
+use crate::bindings::types::{Height, ProtobufAny, StorageValue};
 use crate::interchain_queries::helpers::decode_and_convert;
-use crate::interchain_queries::types::KVReconstruct;
+use crate::interchain_queries::types::{
+    KVReconstruct, KVReconstructValue, TransactionFilterItem, TransactionFilterOp,
+    TransactionFilterValue,
+};
 use crate::interchain_queries::v045::helpers::{
     create_account_denom_balance_key, create_delegation_key, create_fee_pool_key,
     create_gov_proposal_key, create_params_store_key, create_total_denom_key, create_validator_key,
+    get_balance_denom,
+};
+use crate::interchain_queries::v045::new_update_tx_query_min_height_msg;
+use crate::interchain_queries::v045::tx::{
+    decode_tx_query_result, match_tx_messages, tx_matches_filters, verify_tx_query_result,
+    MsgTransfer, TxMessage,
 };
 use crate::interchain_queries::v045::types::{
     Balances, Delegations, FeePool, GovernmentProposal, Proposal, StakingValidator, TallyResult,
-    TotalSupply, Validator as ContractValidator, DECIMAL_PLACES, KEY_BOND_DENOM, STAKING_STORE_KEY,
+    TotalSupply, Validator as ContractValidator, AMOUNT_FIELD, COSMOS_SDK_TRANSFER_MSG_URL,
+    COSMOS_SDK_UNDELEGATE_MSG_URL, DECIMAL_PLACES, DELEGATE_AMOUNT_FIELD, DELEGATE_VALIDATOR_FIELD,
+    HEIGHT_FIELD, IBC_TRANSFER_RECEIVER_FIELD, KEY_BOND_DENOM, MESSAGE_MODULE_FIELD,
+    MESSAGE_SENDER_FIELD, RECIPIENT_FIELD, SENDER_FIELD, STAKING_STORE_KEY,
 };
-use crate::{NeutronError, NeutronResult};
+use crate::{bindings::msg::NeutronMsg, NeutronError, NeutronResult};
 use base64::prelude::*;
 use base64::Engine;
+use cosmos_sdk_proto::cosmos::bank::v1beta1::{Input, MsgMultiSend, MsgSend, Output};
 use cosmos_sdk_proto::cosmos::base::v1beta1::{Coin, DecCoin};
 use cosmos_sdk_proto::cosmos::distribution::v1beta1::FeePool as CosmosFeePool;
 use cosmos_sdk_proto::cosmos::gov::v1beta1::{
     Proposal as CosmosProposal, TallyResult as CosmosTallyResult,
 };
 use cosmos_sdk_proto::cosmos::staking::v1beta1::{
-    Commission, CommissionRates, Delegation, Description, Validator,
+    Commission, CommissionRates, Delegation, Description, MsgDelegate, MsgUndelegate, Validator,
 };
+use cosmos_sdk_proto::cosmos::tx::v1beta1::{AuthInfo, Fee, SignerInfo, TxBody, TxRaw};
 use cosmos_sdk_proto::traits::Message;
+use cosmos_sdk_proto::Any;
 use cosmwasm_std::{
     to_binary, Addr, Binary, Coin as StdCoin, Decimal, Delegation as StdDelegation, Uint128,
 };
@@ -940,3 +955,744 @@ fn test_delegations_reconstruct_from_hex() {
         }
     );
 }
+
+#[test]
+fn test_reconstruct_absent_values() {
+    let addr = decode_and_convert("osmo1yz54ncxj9csp7un3xled03q6thrrhy9cztkfzs").unwrap();
+    let absent = |key: Vec<u8>| StorageValue {
+        storage_prefix: "".to_string(),
+        key: Binary(key),
+        value: Binary::default(),
+    };
+
+    // zero balances are absent in the bank store
+    let balance_values = vec![
+        StorageValue {
+            storage_prefix: "".to_string(),
+            key: Binary(create_account_denom_balance_key(&addr, "uosmo").unwrap()),
+            value: Binary(
+                Coin {
+                    denom: "uosmo".to_string(),
+                    amount: "100".to_string(),
+                }
+                .encode_to_vec(),
+            ),
+        },
+        absent(create_account_denom_balance_key(&addr, "uatom").unwrap()),
+    ];
+    assert_eq!(
+        get_balance_denom(&balance_values[1].key),
+        Some("uatom".to_string())
+    );
+    assert_eq!(
+        Balances::reconstruct(&balance_values).unwrap(),
+        Balances {
+            coins: vec![StdCoin::new(100, "uosmo"), StdCoin::new(0, "uatom")]
+        }
+    );
+    assert_eq!(
+        Vec::<Option<StdCoin>>::reconstruct(&balance_values).unwrap(),
+        vec![Some(StdCoin::new(100, "uosmo")), None]
+    );
+    assert!(Balances::reconstruct(&[absent(vec![])]).is_err());
+
+    // removed validators are skipped
+    let validator = Validator {
+        operator_address: "osmovaloper1r2u5q6t6w0wssrk6l66n3t2q3dw2uqny4gj2e3".to_string(),
+        tokens: "1000000000000000000".to_string(),
+        delegator_shares: "1000000000000000000".to_string(),
+        ..Default::default()
+    };
+    let validator_key = |operator_address: &str| {
+        create_validator_key(decode_and_convert(operator_address).unwrap()).unwrap()
+    };
+    let validator_values = vec![
+        absent(validator_key(
+            "osmovaloper1lzhlnpahvznwfv4jmay2tgaha5kmz5qxwmj9we",
+        )),
+        StorageValue {
+            storage_prefix: STAKING_STORE_KEY.to_string(),
+            key: Binary(validator_key(&validator.operator_address)),
+            value: Binary(validator.encode_to_vec()),
+        },
+    ];
+    let expected_validator = ContractValidator::reconstruct_value(&validator_values[1]).unwrap();
+    assert_eq!(
+        expected_validator.operator_address,
+        validator.operator_address
+    );
+    assert_eq!(
+        StakingValidator::reconstruct(&validator_values).unwrap(),
+        StakingValidator {
+            validators: vec![expected_validator.clone()]
+        }
+    );
+    assert_eq!(
+        Vec::<Option<ContractValidator>>::reconstruct(&validator_values).unwrap(),
+        vec![None, Some(expected_validator)]
+    );
+
+    // removed proposals are skipped
+    let proposal_values = vec![absent(create_gov_proposal_key(1).unwrap())];
+    assert_eq!(
+        GovernmentProposal::reconstruct(&proposal_values).unwrap(),
+        GovernmentProposal { proposals: vec![] }
+    );
+    assert_eq!(
+        Vec::<Option<Proposal>>::reconstruct(&proposal_values).unwrap(),
+        vec![None]
+    );
+
+    // deleted delegations are skipped, even if the validator is removed too
+    let delegation = Delegation {
+        delegator_address: "osmo1yz54ncxj9csp7un3xled03q6thrrhy9cztkfzs".to_string(),
+        validator_address: validator.operator_address.clone(),
+        shares: "1000000000000000000".to_string(),
+    };
+    let denom_value = StorageValue {
+        storage_prefix: "params".to_string(),
+        key: Binary(create_params_store_key(STAKING_STORE_KEY, KEY_BOND_DENOM)),
+        value: to_binary("stake").unwrap(),
+    };
+    let delegation_values = vec![
+        denom_value.clone(),
+        absent(vec![]),
+        absent(vec![]),
+        absent(vec![]),
+        validator_values[1].clone(),
+    ];
+    assert_eq!(
+        Delegations::reconstruct(&delegation_values).unwrap(),
+        Delegations {
+            delegations: vec![]
+        }
+    );
+    // but a delegation to a removed validator is an invalid result
+    let delegation_values = vec![
+        denom_value,
+        StorageValue {
+            storage_prefix: STAKING_STORE_KEY.to_string(),
+            key: Binary::default(),
+            value: Binary(delegation.encode_to_vec()),
+        },
+        absent(vec![]),
+    ];
+    assert_eq!(
+        Delegations::reconstruct(&delegation_values).unwrap_err(),
+        NeutronError::InvalidQueryResultFormat("validator is empty".into())
+    );
+}
+
+fn filter(
+    field: &str,
+    op: TransactionFilterOp,
+    value: TransactionFilterValue,
+) -> TransactionFilterItem {
+    TransactionFilterItem {
+        field: field.to_string(),
+        op,
+        value,
+    }
+}
+
+fn build_tx_messages() -> Vec<TxMessage> {
+    vec![
+        TxMessage::Send(MsgSend {
+            from_address: "sender".to_string(),
+            to_address: "recipient".to_string(),
+            amount: vec![Coin {
+                denom: "stake".to_string(),
+                amount: "10000".to_string(),
+            }],
+        }),
+        TxMessage::MultiSend(MsgMultiSend {
+            inputs: vec![Input {
+                address: "sender".to_string(),
+                coins: vec![Coin {
+                    denom: "stake".to_string(),
+                    amount: "300".to_string(),
+                }],
+            }],
+            outputs: vec![
+                Output {
+                    address: "another_recipient".to_string(),
+                    coins: vec![Coin {
+                        denom: "stake".to_string(),
+                        amount: "100".to_string(),
+                    }],
+                },
+                Output {
+                    address: "recipient".to_string(),
+                    coins: vec![Coin {
+                        denom: "stake".to_string(),
+                        amount: "200".to_string(),
+                    }],
+                },
+            ],
+        }),
+        TxMessage::IbcTransfer(MsgTransfer {
+            source_port: "transfer".to_string(),
+            source_channel: "channel-0".to_string(),
+            token: Some(Coin {
+                denom: "stake".to_string(),
+                amount: "500".to_string(),
+            }),
+            sender: "sender".to_string(),
+            receiver: "remote_recipient".to_string(),
+            timeout_height: None,
+            timeout_timestamp: 0,
+        }),
+        TxMessage::Delegate(MsgDelegate {
+            delegator_address: "sender".to_string(),
+            validator_address: "validator".to_string(),
+            amount: Some(Coin {
+                denom: "stake".to_string(),
+                amount: "1000".to_string(),
+            }),
+        }),
+    ]
+}
+
+#[test]
+fn test_match_tx_messages() {
+    let messages = build_tx_messages();
+    let height = Height {
+        revision_number: 0,
+        revision_height: 100,
+    };
+
+    struct TestCase {
+        filters: Vec<TransactionFilterItem>,
+        expected_result: NeutronResult<Vec<TxMessage>>,
+    }
+    let test_cases: Vec<TestCase> = vec![
+        TestCase {
+            filters: vec![filter(
+                RECIPIENT_FIELD,
+                TransactionFilterOp::Eq,
+                TransactionFilterValue::String("recipient".to_string()),
+            )],
+            expected_result: Ok(vec![messages[0].clone(), messages[1].clone()]),
+        },
+        TestCase {
+            filters: vec![
+                filter(
+                    RECIPIENT_FIELD,
+                    TransactionFilterOp::Eq,
+                    TransactionFilterValue::String("recipient".to_string()),
+                ),
+                filter(
+                    MESSAGE_MODULE_FIELD,
+                    TransactionFilterOp::Eq,
+                    TransactionFilterValue::String("bank".to_string()),
+                ),
+                filter(
+                    AMOUNT_FIELD,
+                    TransactionFilterOp::Gt,
+                    TransactionFilterValue::Int(1000),
+                ),
+            ],
+            expected_result: Ok(vec![messages[0].clone()]),
+        },
+        TestCase {
+            filters: vec![
+                filter(
+                    RECIPIENT_FIELD,
+                    TransactionFilterOp::Eq,
+                    TransactionFilterValue::String("recipient".to_string()),
+                ),
+                filter(
+                    MESSAGE_MODULE_FIELD,
+                    TransactionFilterOp::Eq,
+                    TransactionFilterValue::String("bank".to_string()),
+                ),
+                filter(
+                    AMOUNT_FIELD,
+                    TransactionFilterOp::Gt,
+                    TransactionFilterValue::Int(-1),
+                ),
+                filter(
+                    AMOUNT_FIELD,
+                    TransactionFilterOp::Lt,
+                    TransactionFilterValue::BigInt(Uint128::MAX),
+                ),
+            ],
+            expected_result: Ok(vec![messages[0].clone(), messages[1].clone()]),
+        },
+        TestCase {
+            filters: vec![filter(
+                AMOUNT_FIELD,
+                TransactionFilterOp::Gte,
+                TransactionFilterValue::BigInt(Uint128::new(u64::MAX as u128 + 1)),
+            )],
+            expected_result: Ok(vec![]),
+        },
+        // MultiSend and Delegate don't emit `transfer.sender`
+        TestCase {
+            filters: vec![filter(
+                SENDER_FIELD,
+                TransactionFilterOp::Eq,
+                TransactionFilterValue::String("sender".to_string()),
+            )],
+            expected_result: Ok(vec![messages[0].clone(), messages[2].clone()]),
+        },
+        TestCase {
+            filters: vec![filter(
+                MESSAGE_SENDER_FIELD,
+                TransactionFilterOp::Eq,
+                TransactionFilterValue::String("sender".to_string()),
+            )],
+            expected_result: Ok(messages.clone()),
+        },
+        TestCase {
+            filters: vec![filter(
+                IBC_TRANSFER_RECEIVER_FIELD,
+                TransactionFilterOp::Eq,
+                TransactionFilterValue::String("remote_recipient".to_string()),
+            )],
+            expected_result: Ok(vec![messages[2].clone()]),
+        },
+        TestCase {
+            filters: vec![
+                filter(
+                    DELEGATE_VALIDATOR_FIELD,
+                    TransactionFilterOp::Eq,
+                    TransactionFilterValue::String("validator".to_string()),
+                ),
+                filter(
+                    DELEGATE_AMOUNT_FIELD,
+                    TransactionFilterOp::Gte,
+                    TransactionFilterValue::Int(1000),
+                ),
+            ],
+            expected_result: Ok(vec![messages[3].clone()]),
+        },
+        TestCase {
+            filters: vec![
+                filter(
+                    RECIPIENT_FIELD,
+                    TransactionFilterOp::Eq,
+                    TransactionFilterValue::String("recipient".to_string()),
+                ),
+                filter(
+                    HEIGHT_FIELD,
+                    TransactionFilterOp::Gte,
+                    TransactionFilterValue::Int(100),
+                ),
+            ],
+            expected_result: Ok(vec![messages[0].clone(), messages[1].clone()]),
+        },
+        TestCase {
+            filters: vec![
+                filter(
+                    RECIPIENT_FIELD,
+                    TransactionFilterOp::Eq,
+                    TransactionFilterValue::String("recipient".to_string()),
+                ),
+                filter(
+                    HEIGHT_FIELD,
+                    TransactionFilterOp::Gt,
+                    TransactionFilterValue::Int(100),
+                ),
+            ],
+            expected_result: Ok(vec![]),
+        },
+        TestCase {
+            filters: vec![filter(
+                RECIPIENT_FIELD,
+                TransactionFilterOp::Lt,
+                TransactionFilterValue::String("recipient".to_string()),
+            )],
+            expected_result: Ok(vec![]),
+        },
+        TestCase {
+            filters: vec![filter(
+                "coin_received.receiver",
+                TransactionFilterOp::Eq,
+                TransactionFilterValue::String("recipient".to_string()),
+            )],
+            expected_result: Err(NeutronError::UnsupportedTransactionFilterField {
+                field: "coin_received.receiver".to_string(),
+            }),
+        },
+    ];
+
+    for ts in test_cases {
+        let result = match_tx_messages(&messages, &height, &ts.filters);
+        assert_eq!(result, ts.expected_result)
+    }
+}
+
+#[test]
+fn test_unemitted_attributes_do_not_match() {
+    let messages = build_tx_messages();
+    let height = Height {
+        revision_number: 0,
+        revision_height: 100,
+    };
+    let sender_filter = filter(
+        SENDER_FIELD,
+        TransactionFilterOp::Eq,
+        TransactionFilterValue::String("sender".to_string()),
+    );
+    let amount_filter = filter(
+        AMOUNT_FIELD,
+        TransactionFilterOp::Gte,
+        TransactionFilterValue::Int(1),
+    );
+
+    // a relayer can't pass a MultiSend or a Delegate off as a transfer from the sender
+    let multi_send = vec![messages[1].clone()];
+    let delegate = vec![messages[3].clone()];
+    for (tx, filters) in [
+        (&multi_send, vec![sender_filter.clone()]),
+        (&delegate, vec![sender_filter.clone()]),
+        (&delegate, vec![amount_filter.clone()]),
+        (&delegate, vec![sender_filter, amount_filter]),
+    ] {
+        assert_eq!(match_tx_messages(tx, &height, &filters), Ok(vec![]));
+        assert_eq!(tx_matches_filters(tx, &height, &filters), Ok(false));
+    }
+}
+
+#[test]
+fn test_tx_matches_filters() {
+    let messages = build_tx_messages();
+    let height = Height {
+        revision_number: 0,
+        revision_height: 100,
+    };
+    let string_filter = |field: &str, value: &str| {
+        filter(
+            field,
+            TransactionFilterOp::Eq,
+            TransactionFilterValue::String(value.to_string()),
+        )
+    };
+
+    struct TestCase {
+        filters: Vec<TransactionFilterItem>,
+        // Tendermint evaluates every filter against the whole transaction
+        tx_matches: bool,
+        // the SDK returns only messages satisfying all the filters on their own
+        matched_messages: Vec<TxMessage>,
+    }
+    let test_cases = vec![
+        // the recipient is in the MultiSend message, the validator is in the Delegate one
+        TestCase {
+            filters: vec![
+                string_filter(RECIPIENT_FIELD, "another_recipient"),
+                string_filter(DELEGATE_VALIDATOR_FIELD, "validator"),
+            ],
+            tx_matches: true,
+            matched_messages: vec![],
+        },
+        // attributes of a MultiSend are matched independently: the amount belongs to another output
+        TestCase {
+            filters: vec![
+                string_filter(MESSAGE_SENDER_FIELD, "sender"),
+                string_filter(RECIPIENT_FIELD, "another_recipient"),
+                filter(
+                    AMOUNT_FIELD,
+                    TransactionFilterOp::Gt,
+                    TransactionFilterValue::Int(150),
+                ),
+            ],
+            tx_matches: true,
+            matched_messages: vec![messages[1].clone()],
+        },
+        TestCase {
+            filters: vec![
+                string_filter(RECIPIENT_FIELD, "recipient"),
+                filter(
+                    HEIGHT_FIELD,
+                    TransactionFilterOp::Eq,
+                    TransactionFilterValue::Int(100),
+                ),
+            ],
+            tx_matches: true,
+            matched_messages: vec![messages[0].clone(), messages[1].clone()],
+        },
+        TestCase {
+            filters: vec![
+                string_filter(RECIPIENT_FIELD, "recipient"),
+                filter(
+                    HEIGHT_FIELD,
+                    TransactionFilterOp::Lt,
+                    TransactionFilterValue::Int(100),
+                ),
+            ],
+            tx_matches: false,
+            matched_messages: vec![],
+        },
+        TestCase {
+            filters: vec![
+                string_filter(RECIPIENT_FIELD, "recipient"),
+                string_filter(DELEGATE_VALIDATOR_FIELD, "another_validator"),
+            ],
+            tx_matches: false,
+            matched_messages: vec![],
+        },
+    ];
+
+    for tc in test_cases {
+        assert_eq!(
+            tx_matches_filters(&messages, &height, &tc.filters),
+            Ok(tc.tx_matches)
+        );
+        assert_eq!(
+            match_tx_messages(&messages, &height, &tc.filters),
+            Ok(tc.matched_messages)
+        );
+    }
+
+    assert_eq!(
+        tx_matches_filters(
+            &messages,
+            &height,
+            &[string_filter("coin_received.receiver", "recipient")]
+        ),
+        Err(NeutronError::UnsupportedTransactionFilterField {
+            field: "coin_received.receiver".to_string(),
+        })
+    );
+}
+
+#[test]
+fn test_verify_tx_query_result() {
+    let send = MsgSend {
+        from_address: "sender".to_string(),
+        to_address: "recipient".to_string(),
+        amount: vec![Coin {
+            denom: "stake".to_string(),
+            amount: "10000".to_string(),
+        }],
+    };
+    let body = TxBody {
+        messages: vec![
+            Any {
+                type_url: COSMOS_SDK_TRANSFER_MSG_URL.to_string(),
+                value: send.encode_to_vec(),
+            },
+            Any {
+                type_url: "/cosmwasm.wasm.v1.MsgExecuteContract".to_string(),
+                value: vec![1, 2, 3],
+            },
+            Any {
+                type_url: COSMOS_SDK_TRANSFER_MSG_URL.to_string(),
+                value: send.encode_to_vec(),
+            },
+        ],
+        ..Default::default()
+    };
+    let tx = TxRaw {
+        body_bytes: body.encode_to_vec(),
+        auth_info_bytes: vec![],
+        signatures: vec![],
+    };
+    let height = Height {
+        revision_number: 0,
+        revision_height: 100,
+    };
+    let transactions_filter = r#"[{"field":"transfer.recipient","op":"Eq","value":"recipient"}]"#;
+
+    // only first two messages are processed
+    let matched = verify_tx_query_result(&tx, &height, transactions_filter, 2).unwrap();
+    assert_eq!(matched, vec![TxMessage::Send(send.clone())]);
+
+    let matched = verify_tx_query_result(&tx, &height, transactions_filter, 3).unwrap();
+    assert_eq!(
+        matched,
+        vec![TxMessage::Send(send.clone()), TxMessage::Send(send)]
+    );
+
+    let transactions_filter = r#"[{"field":"transfer.recipient","op":"Eq","value":"spoofed"}]"#;
+    let matched = verify_tx_query_result(&tx, &height, transactions_filter, 3).unwrap();
+    assert!(matched.is_empty());
+}
+
+#[test]
+fn test_decode_tx_query_result() {
+    let send = MsgSend {
+        from_address: "sender".to_string(),
+        to_address: "recipient".to_string(),
+        amount: vec![Coin {
+            denom: "stake".to_string(),
+            amount: "10000".to_string(),
+        }],
+    };
+    let undelegate = MsgUndelegate {
+        delegator_address: "sender".to_string(),
+        validator_address: "validator".to_string(),
+        amount: Some(Coin {
+            denom: "stake".to_string(),
+            amount: "100".to_string(),
+        }),
+    };
+    let unknown = Any {
+        type_url: "/cosmwasm.wasm.v1.MsgExecuteContract".to_string(),
+        value: vec![1, 2, 3],
+    };
+    let body = TxBody {
+        messages: vec![
+            Any {
+                type_url: COSMOS_SDK_TRANSFER_MSG_URL.to_string(),
+                value: send.encode_to_vec(),
+            },
+            Any {
+                type_url: COSMOS_SDK_UNDELEGATE_MSG_URL.to_string(),
+                value: undelegate.encode_to_vec(),
+            },
+            unknown.clone(),
+            unknown.clone(),
+        ],
+        memo: "memo".to_string(),
+        timeout_height: 1000,
+        ..Default::default()
+    };
+    let fee = Fee {
+        amount: vec![Coin {
+            denom: "stake".to_string(),
+            amount: "1000".to_string(),
+        }],
+        gas_limit: 200000,
+        payer: "".to_string(),
+        granter: "".to_string(),
+    };
+    let auth_info = AuthInfo {
+        signer_infos: vec![SignerInfo {
+            public_key: None,
+            mode_info: None,
+            sequence: 42,
+        }],
+        fee: Some(fee.clone()),
+        tip: None,
+    };
+    let data = Binary::from(
+        TxRaw {
+            body_bytes: body.encode_to_vec(),
+            auth_info_bytes: auth_info.encode_to_vec(),
+            signatures: vec![vec![0; 64]],
+        }
+        .encode_to_vec(),
+    );
+
+    let tx = decode_tx_query_result(&data, 3).unwrap();
+    assert_eq!(tx.body, body);
+    assert_eq!(tx.auth_info, auth_info);
+    assert_eq!(tx.memo, "memo".to_string());
+    assert_eq!(tx.timeout_height, 1000);
+    assert_eq!(tx.fee, Some(fee));
+    assert_eq!(tx.signer_sequences, vec![42]);
+    assert_eq!(
+        tx.messages,
+        vec![
+            TxMessage::Send(send),
+            TxMessage::Undelegate(undelegate),
+            TxMessage::Unknown(ProtobufAny::new(
+                unknown.type_url,
+                Binary::from(unknown.value)
+            )),
+        ]
+    );
+    assert_eq!(tx.skipped_messages, 1);
+
+    let tx = decode_tx_query_result(&data, 0).unwrap();
+    assert!(tx.messages.is_empty());
+    assert_eq!(tx.skipped_messages, 4);
+
+    assert!(decode_tx_query_result(&Binary::from(vec![0xff, 0xff]), 3).is_err());
+}
+
+#[test]
+fn test_new_update_tx_query_min_height_msg() {
+    let recipient = || {
+        filter(
+            RECIPIENT_FIELD,
+            TransactionFilterOp::Eq,
+            TransactionFilterValue::String("recipient".to_string()),
+        )
+    };
+    let max_height = || {
+        filter(
+            HEIGHT_FIELD,
+            TransactionFilterOp::Lte,
+            TransactionFilterValue::Int(1000),
+        )
+    };
+    let min_height = |op: TransactionFilterOp, height: u64| filter(HEIGHT_FIELD, op, height.into());
+    let update_msg = |filters: Vec<TransactionFilterItem>| {
+        Some(NeutronMsg::UpdateInterchainQuery {
+            query_id: 1,
+            new_keys: None,
+            new_update_period: None,
+            new_transactions_filter: Some(serde_json_wasm::to_string(&filters).unwrap()),
+        })
+    };
+
+    struct TestCase {
+        filters: Vec<TransactionFilterItem>,
+        height: u64,
+        expected_result: Option<NeutronMsg>,
+    }
+    let test_cases: Vec<TestCase> = vec![
+        // no lower bound yet
+        TestCase {
+            filters: vec![recipient(), max_height()],
+            height: 150,
+            expected_result: update_msg(vec![
+                recipient(),
+                max_height(),
+                min_height(TransactionFilterOp::Gte, 150),
+            ]),
+        },
+        // lower bound is bumped, upper bound is preserved
+        TestCase {
+            filters: vec![
+                recipient(),
+                min_height(TransactionFilterOp::Gte, 100),
+                max_height(),
+            ],
+            height: 150,
+            expected_result: update_msg(vec![
+                recipient(),
+                max_height(),
+                min_height(TransactionFilterOp::Gte, 150),
+            ]),
+        },
+        TestCase {
+            filters: vec![recipient(), min_height(TransactionFilterOp::Gt, 100)],
+            height: 150,
+            expected_result: update_msg(vec![
+                recipient(),
+                min_height(TransactionFilterOp::Gte, 150),
+            ]),
+        },
+        // lower bound is already up to date
+        TestCase {
+            filters: vec![recipient(), min_height(TransactionFilterOp::Gte, 150)],
+            height: 150,
+            expected_result: None,
+        },
+        TestCase {
+            filters: vec![recipient(), min_height(TransactionFilterOp::Gt, 149)],
+            height: 150,
+            expected_result: None,
+        },
+        TestCase {
+            filters: vec![recipient(), min_height(TransactionFilterOp::Gte, 200)],
+            height: 150,
+            expected_result: None,
+        },
+    ];
+
+    for ts in test_cases {
+        let msg = new_update_tx_query_min_height_msg(
+            1,
+            &serde_json_wasm::to_string(&ts.filters).unwrap(),
+            ts.height,
+        )
+        .unwrap();
+        assert_eq!(msg, ts.expected_result)
+    }
+}
@@ -0,0 +1,382 @@
+use crate::bindings::types::{Height, ProtobufAny};
+use crate::errors::error::{NeutronError, NeutronResult};
+use crate::interchain_queries::types::{
+    TransactionFilterItem, TransactionFilterOp, TransactionFilterValue,
+};
+use crate::interchain_queries::v045::types::{
+    AMOUNT_FIELD, BANK_MODULE_NAME, COSMOS_SDK_BEGIN_REDELEGATE_MSG_URL,
+    COSMOS_SDK_DELEGATE_MSG_URL, COSMOS_SDK_MULTI_SEND_MSG_URL, COSMOS_SDK_TRANSFER_MSG_URL,
+    COSMOS_SDK_UNDELEGATE_MSG_URL, COSMOS_SDK_VOTE_MSG_URL,
+    COSMOS_SDK_WITHDRAW_DELEGATOR_REWARD_MSG_URL, DELEGATE_AMOUNT_FIELD, DELEGATE_VALIDATOR_FIELD,
+    DISTRIBUTION_MODULE_NAME, GOV_MODULE_NAME, HEIGHT_FIELD, IBC_TRANSFER_MODULE_NAME,
+    IBC_TRANSFER_MSG_URL, IBC_TRANSFER_RECEIVER_FIELD, IBC_TRANSFER_SENDER_FIELD,
+    MESSAGE_MODULE_FIELD, MESSAGE_SENDER_FIELD, RECIPIENT_FIELD, SENDER_FIELD, STAKING_MODULE_NAME,
+};
+use cosmos_sdk_proto::cosmos::bank::v1beta1::{MsgMultiSend, MsgSend};
+use cosmos_sdk_proto::cosmos::base::v1beta1::Coin as CosmosCoin;
+use cosmos_sdk_proto::cosmos::distribution::v1beta1::MsgWithdrawDelegatorReward;
+use cosmos_sdk_proto::cosmos::gov::v1beta1::MsgVote;
+use cosmos_sdk_proto::cosmos::staking::v1beta1::{MsgBeginRedelegate, MsgDelegate, MsgUndelegate};
+use cosmos_sdk_proto::cosmos::tx::v1beta1::{AuthInfo, Fee, TxBody, TxRaw};
+use cosmos_sdk_proto::traits::Message;
+use cosmos_sdk_proto::Any;
+use cosmwasm_std::Binary;
+use std::cmp::Ordering;
+
+/// Transaction filter fields which can be evaluated against decoded transaction messages
+const SUPPORTED_FILTER_FIELDS: [&str; 10] = [
+    RECIPIENT_FIELD,
+    SENDER_FIELD,
+    AMOUNT_FIELD,
+    MESSAGE_SENDER_FIELD,
+    MESSAGE_MODULE_FIELD,
+    DELEGATE_VALIDATOR_FIELD,
+    DELEGATE_AMOUNT_FIELD,
+    IBC_TRANSFER_SENDER_FIELD,
+    IBC_TRANSFER_RECEIVER_FIELD,
+    HEIGHT_FIELD,
+];
+
+/// IBC fungible token transfer message
+/// <https://github.com/cosmos/ibc-go/blob/46e020640e66f9043c14c53a4d215a5b457d6703/proto/ibc/applications/transfer/v1/tx.proto#L23>.
+/// The `memo` field isn't decoded.
+pub use cosmos_sdk_proto::ibc::applications::transfer::v1::MsgTransfer;
+
+#[derive(Clone, Debug, PartialEq)]
+/// A message of a remote chain transaction decoded into one of the known Cosmos-SDK types.
+/// Messages of any other type are kept as is in the **Unknown** variant.
+pub enum TxMessage {
+    Send(MsgSend),
+    MultiSend(MsgMultiSend),
+    IbcTransfer(MsgTransfer),
+    Delegate(MsgDelegate),
+    Undelegate(MsgUndelegate),
+    BeginRedelegate(MsgBeginRedelegate),
+    WithdrawDelegatorReward(MsgWithdrawDelegatorReward),
+    Vote(MsgVote),
+    Unknown(ProtobufAny),
+}
+
+impl TxMessage {
+    /// Decodes protobuf **msg** into one of the known message types using its type url
+    pub fn decode(msg: &Any) -> NeutronResult<TxMessage> {
+        let value = msg.value.as_slice();
+
+        Ok(match msg.type_url.as_str() {
+            COSMOS_SDK_TRANSFER_MSG_URL => TxMessage::Send(MsgSend::decode(value)?),
+            COSMOS_SDK_MULTI_SEND_MSG_URL => TxMessage::MultiSend(MsgMultiSend::decode(value)?),
+            IBC_TRANSFER_MSG_URL => TxMessage::IbcTransfer(MsgTransfer::decode(value)?),
+            COSMOS_SDK_DELEGATE_MSG_URL => TxMessage::Delegate(MsgDelegate::decode(value)?),
+            COSMOS_SDK_UNDELEGATE_MSG_URL => TxMessage::Undelegate(MsgUndelegate::decode(value)?),
+            COSMOS_SDK_BEGIN_REDELEGATE_MSG_URL => {
+                TxMessage::BeginRedelegate(MsgBeginRedelegate::decode(value)?)
+            }
+            COSMOS_SDK_WITHDRAW_DELEGATOR_REWARD_MSG_URL => {
+                TxMessage::WithdrawDelegatorReward(MsgWithdrawDelegatorReward::decode(value)?)
+            }
+            COSMOS_SDK_VOTE_MSG_URL => TxMessage::Vote(MsgVote::decode(value)?),
+            _ => TxMessage::Unknown(ProtobufAny::new(
+                msg.type_url.clone(),
+                Binary::from(msg.value.clone()),
+            )),
+        })
+    }
+
+    /// Returns values of the event attribute **field** which Cosmos-SDK emits while executing the message.
+    /// Empty result means the message does not emit such an attribute at all.
+    fn attribute_values(&self, field: &str) -> Vec<String> {
+        match (self, field) {
+            (TxMessage::Send(msg), RECIPIENT_FIELD) => vec![msg.to_address.clone()],
+            (TxMessage::Send(msg), SENDER_FIELD | MESSAGE_SENDER_FIELD) => {
+                vec![msg.from_address.clone()]
+            }
+            (TxMessage::Send(msg), AMOUNT_FIELD) => vec![coins_to_string(&msg.amount)],
+            (TxMessage::Send(_) | TxMessage::MultiSend(_), MESSAGE_MODULE_FIELD) => {
+                vec![BANK_MODULE_NAME.to_string()]
+            }
+
+            (TxMessage::MultiSend(msg), RECIPIENT_FIELD) => {
+                msg.outputs.iter().map(|o| o.address.clone()).collect()
+            }
+            // inputs emit only `message.sender`, there is no `transfer.sender` for a MultiSend
+            (TxMessage::MultiSend(msg), MESSAGE_SENDER_FIELD) => {
+                msg.inputs.iter().map(|i| i.address.clone()).collect()
+            }
+            (TxMessage::MultiSend(msg), AMOUNT_FIELD) => msg
+                .outputs
+                .iter()
+                .map(|o| coins_to_string(&o.coins))
+                .collect(),
+
+            (
+                TxMessage::IbcTransfer(msg),
+                SENDER_FIELD | MESSAGE_SENDER_FIELD | IBC_TRANSFER_SENDER_FIELD,
+            ) => vec![msg.sender.clone()],
+            (TxMessage::IbcTransfer(msg), IBC_TRANSFER_RECEIVER_FIELD) => {
+                vec![msg.receiver.clone()]
+            }
+            (TxMessage::IbcTransfer(msg), AMOUNT_FIELD) => {
+                msg.token.iter().map(coin_to_string).collect()
+            }
+            (TxMessage::IbcTransfer(_), MESSAGE_MODULE_FIELD) => {
+                vec![IBC_TRANSFER_MODULE_NAME.to_string()]
+            }
+
+            // delegated coins are moved by DelegateCoins, which doesn't emit `transfer` events
+            (TxMessage::Delegate(msg), MESSAGE_SENDER_FIELD) => {
+                vec![msg.delegator_address.clone()]
+            }
+            (TxMessage::Delegate(msg), DELEGATE_VALIDATOR_FIELD) => {
+                vec![msg.validator_address.clone()]
+            }
+            (TxMessage::Delegate(msg), DELEGATE_AMOUNT_FIELD) => {
+                msg.amount.iter().map(|c| c.amount.clone()).collect()
+            }
+            (TxMessage::Undelegate(msg), MESSAGE_SENDER_FIELD) => {
+                vec![msg.delegator_address.clone()]
+            }
+            (TxMessage::BeginRedelegate(msg), MESSAGE_SENDER_FIELD) => {
+                vec![msg.delegator_address.clone()]
+            }
+            (
+                TxMessage::Delegate(_) | TxMessage::Undelegate(_) | TxMessage::BeginRedelegate(_),
+                MESSAGE_MODULE_FIELD,
+            ) => vec![STAKING_MODULE_NAME.to_string()],
+
+            (TxMessage::WithdrawDelegatorReward(msg), MESSAGE_SENDER_FIELD) => {
+                vec![msg.delegator_address.clone()]
+            }
+            (TxMessage::WithdrawDelegatorReward(_), MESSAGE_MODULE_FIELD) => {
+                vec![DISTRIBUTION_MODULE_NAME.to_string()]
+            }
+
+            (TxMessage::Vote(msg), MESSAGE_SENDER_FIELD) => vec![msg.voter.clone()],
+            (TxMessage::Vote(_), MESSAGE_MODULE_FIELD) => vec![GOV_MODULE_NAME.to_string()],
+
+            _ => vec![],
+        }
+    }
+
+    /// Checks whether the message satisfies every filter of the **filters** list.
+    /// The **tx.height** filter is not related to messages and is ignored here.
+    fn matches(&self, filters: &[TransactionFilterItem]) -> bool {
+        filters.iter().filter(|f| f.field != HEIGHT_FIELD).all(|f| {
+            self.attribute_values(&f.field)
+                .iter()
+                .any(|v| value_matches(v, &f.op, &f.value))
+        })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+/// A remote chain transaction submitted via `SudoMsg::TxQueryResult` decoded into typed structures.
+pub struct DecodedTx {
+    /// **body** is a raw body of the transaction with all its messages
+    pub body: TxBody,
+    /// **auth_info** is an authorization related content of the transaction
+    pub auth_info: AuthInfo,
+    /// **memo** is a memo attached to the transaction
+    pub memo: String,
+    /// **timeout_height** is a block height after which the transaction is not valid anymore (0 if not set)
+    pub timeout_height: u64,
+    /// **fee** is a fee paid for the transaction
+    pub fee: Option<Fee>,
+    /// **signer_sequences** are sequences of the transaction signers in the order of signatures
+    pub signer_sequences: Vec<u64>,
+    /// **messages** are first `max_messages` messages of the transaction decoded into known types
+    pub messages: Vec<TxMessage>,
+    /// **skipped_messages** is a number of messages which were not decoded due to `max_messages` limit
+    pub skipped_messages: usize,
+}
+
+/// Decodes **data** of `SudoMsg::TxQueryResult` into [DecodedTx].
+///
+/// Only first **max_messages** messages of the transaction are decoded into [TxMessage]'s,
+/// the rest are counted in [DecodedTx::skipped_messages] and still available in raw form
+/// in [DecodedTx::body]. Limiting amount of processed messages protects contracts from
+/// 'out of gas' conditions when a transaction contains a lot of messages.
+pub fn decode_tx_query_result(data: &Binary, max_messages: usize) -> NeutronResult<DecodedTx> {
+    let tx: TxRaw = TxRaw::decode(data.as_slice())?;
+    let body: TxBody = TxBody::decode(tx.body_bytes.as_slice())?;
+    let auth_info: AuthInfo = AuthInfo::decode(tx.auth_info_bytes.as_slice())?;
+
+    let messages = decode_messages(&body, max_messages)?;
+
+    Ok(DecodedTx {
+        memo: body.memo.clone(),
+        timeout_height: body.timeout_height,
+        fee: auth_info.fee.clone(),
+        signer_sequences: auth_info.signer_infos.iter().map(|s| s.sequence).collect(),
+        skipped_messages: body.messages.len() - messages.len(),
+        messages,
+        body,
+        auth_info,
+    })
+}
+
+/// Decodes first **max_messages** messages of transaction **body**
+fn decode_messages(body: &TxBody, max_messages: usize) -> NeutronResult<Vec<TxMessage>> {
+    body.messages
+        .iter()
+        .take(max_messages)
+        .map(TxMessage::decode)
+        .collect()
+}
+
+/// Evaluates transactions **filters** of a registered TX Interchain Query against already decoded
+/// **messages** of a transaction included in a remote chain block at **height**.
+/// Returns the messages which satisfy all the filters.
+///
+/// Filters are evaluated per message, so a message matches only if it emits every filtered event
+/// attribute itself. This differs from Tendermint, which evaluates every filter against the events
+/// of the whole transaction independently, see [tx_matches_filters]:
+/// * a transaction with e.g. a transfer to the recipient in one message and a big enough amount
+///   in another one satisfies the filters on the chain, but no message of it is returned here;
+/// * attributes of a single `MultiSend` message are matched independently as well, so a `MultiSend`
+///   with the filtered `message.sender` in one input and the filtered amount in an unrelated output
+///   is returned.
+///   Contracts should pair inputs and outputs of returned `MultiSend` messages themselves.
+///
+/// Returns an error if a filter refers to an event attribute which can't be evaluated by the SDK,
+/// because such a filter can't be verified.
+pub fn match_tx_messages(
+    messages: &[TxMessage],
+    height: &Height,
+    filters: &[TransactionFilterItem],
+) -> NeutronResult<Vec<TxMessage>> {
+    check_filter_fields(filters)?;
+    if !height_matches(height, filters) {
+        return Ok(vec![]);
+    }
+
+    Ok(messages
+        .iter()
+        .filter(|msg| msg.matches(filters))
+        .cloned()
+        .collect())
+}
+
+/// Evaluates transactions **filters** of a registered TX Interchain Query against already decoded
+/// **messages** of a transaction included in a remote chain block at **height** the way Tendermint
+/// does it: every filter is satisfied if any message of the transaction emits a matching attribute.
+/// Use [match_tx_messages] to get the messages which satisfy all the filters on their own.
+///
+/// Returns an error if a filter refers to an event attribute which can't be evaluated by the SDK.
+pub fn tx_matches_filters(
+    messages: &[TxMessage],
+    height: &Height,
+    filters: &[TransactionFilterItem],
+) -> NeutronResult<bool> {
+    check_filter_fields(filters)?;
+
+    Ok(height_matches(height, filters)
+        && filters.iter().filter(|f| f.field != HEIGHT_FIELD).all(|f| {
+            messages
+                .iter()
+                .any(|msg| msg.matches(std::slice::from_ref(f)))
+        }))
+}
+
+fn check_filter_fields(filters: &[TransactionFilterItem]) -> NeutronResult<()> {
+    match filters
+        .iter()
+        .find(|f| !SUPPORTED_FILTER_FIELDS.contains(&f.field.as_str()))
+    {
+        Some(f) => Err(NeutronError::UnsupportedTransactionFilterField {
+            field: f.field.clone(),
+        }),
+        None => Ok(()),
+    }
+}
+
+fn height_matches(height: &Height, filters: &[TransactionFilterItem]) -> bool {
+    filters
+        .iter()
+        .filter(|f| f.field == HEIGHT_FIELD)
+        .all(|f| value_matches(&height.revision_height.to_string(), &f.op, &f.value))
+}
+
+/// Verifies transaction **tx** submitted by a relayer via `SudoMsg::TxQueryResult` at **height**
+/// against the **transactions_filter** of the registered query, i.e. `RegisteredQuery.transactions_filter`.
+/// Returns the decoded messages which satisfy the filter. Empty result means the submitted transaction
+/// does not satisfy the filter and should be rejected by the contract.
+///
+/// Only first **max_messages** messages of the transaction are processed, everything else is ignored
+/// to prevent 'out of gas' conditions.
+pub fn verify_tx_query_result(
+    tx: &TxRaw,
+    height: &Height,
+    transactions_filter: &str,
+    max_messages: usize,
+) -> NeutronResult<Vec<TxMessage>> {
+    let filters: Vec<TransactionFilterItem> = serde_json_wasm::from_str(transactions_filter)?;
+    let body: TxBody = TxBody::decode(tx.body_bytes.as_slice())?;
+    let messages = decode_messages(&body, max_messages)?;
+
+    match_tx_messages(&messages, height, &filters)
+}
+
+/// Checks the event attribute value **actual** against the filter value **expected** using **op**.
+/// String filter values support only **Eq** operation. Integer filter values are compared with the
+/// (possibly negative) number the attribute value starts with, so `transfer.amount > 100` matches
+/// `1000stake` the same way Tendermint does it.
+fn value_matches(
+    actual: &str,
+    op: &TransactionFilterOp,
+    expected: &TransactionFilterValue,
+) -> bool {
+    let ordering = match (expected, expected.sign_and_magnitude()) {
+        (TransactionFilterValue::String(expected), _) => {
+            return *op == TransactionFilterOp::Eq && actual == expected
+        }
+        (_, Some((negative, magnitude))) => compare_int(actual, negative, magnitude),
+        (_, None) => None,
+    };
+
+    match ordering {
+        Some(ordering) => match op {
+            TransactionFilterOp::Eq => ordering == Ordering::Equal,
+            TransactionFilterOp::Lt => ordering == Ordering::Less,
+            TransactionFilterOp::Gt => ordering == Ordering::Greater,
+            TransactionFilterOp::Lte => ordering != Ordering::Greater,
+            TransactionFilterOp::Gte => ordering != Ordering::Less,
+        },
+        None => false,
+    }
+}
+
+/// Compares the number **actual** starts with against the integer described by its sign and magnitude.
+/// Returns None if **actual** does not start with a number.
+fn compare_int(
+    actual: &str,
+    expected_negative: bool,
+    expected_magnitude: u128,
+) -> Option<Ordering> {
+    let (negative, rest) = match actual.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, actual),
+    };
+    let digits: String = rest.chars().take_while(|c| c.is_ascii_digit()).collect();
+    let magnitude = digits.parse::<u128>().ok()?;
+
+    Some(match (negative && magnitude != 0, expected_negative) {
+        (false, false) => magnitude.cmp(&expected_magnitude),
+        (true, true) => expected_magnitude.cmp(&magnitude),
+        (false, true) => Ordering::Greater,
+        (true, false) => Ordering::Less,
+    })
+}
+
+/// Formats coin the same way Cosmos-SDK does it in events, e.g. `100stake`
+fn coin_to_string(coin: &CosmosCoin) -> String {
+    format!("{}{}", coin.amount, coin.denom)
+}
+
+/// Formats coins the same way Cosmos-SDK does it in events, e.g. `100stake,10uatom`
+fn coins_to_string(coins: &[CosmosCoin]) -> String {
+    coins
+        .iter()
+        .map(coin_to_string)
+        .collect::<Vec<String>>()
+        .join(",")
+}
@@ -1,4 +1,4 @@
-use crate::interchain_queries::types::KVReconstruct;
+use crate::interchain_queries::types::{KVReconstruct, KVReconstructValue};
 use crate::{
     bindings::types::StorageValue,
     errors::error::{NeutronError, NeutronResult},
@@ -16,8 +16,8 @@ use serde::{Deserialize, Serialize};
 use std::{ops::Div, str::FromStr};
 
 use super::helpers::{
-    get_max_change_rate, get_max_rate, get_rate, get_total_supply_amount, get_total_supply_denom,
-    get_update_time,
+    get_balance_denom, get_max_change_rate, get_max_rate, get_rate, get_total_supply_amount,
+    get_total_supply_denom, get_update_time,
 };
 
 pub const DECIMAL_PLACES: u32 = 18;
@@ -26,6 +26,28 @@ const DECIMAL_FRACTIONAL: u128 = 10u128.pow(DECIMAL_PLACES);
 /// Protobuf type url of standard Cosmos SDK bank transfer message
 pub const COSMOS_SDK_TRANSFER_MSG_URL: &str = "/cosmos.bank.v1beta1.MsgSend";
 
+/// Protobuf type url of standard Cosmos SDK bank multi send message
+pub const COSMOS_SDK_MULTI_SEND_MSG_URL: &str = "/cosmos.bank.v1beta1.MsgMultiSend";
+
+/// Protobuf type url of standard Cosmos SDK staking delegate message
+pub const COSMOS_SDK_DELEGATE_MSG_URL: &str = "/cosmos.staking.v1beta1.MsgDelegate";
+
+/// Protobuf type url of standard Cosmos SDK staking undelegate message
+pub const COSMOS_SDK_UNDELEGATE_MSG_URL: &str = "/cosmos.staking.v1beta1.MsgUndelegate";
+
+/// Protobuf type url of standard Cosmos SDK staking redelegate message
+pub const COSMOS_SDK_BEGIN_REDELEGATE_MSG_URL: &str = "/cosmos.staking.v1beta1.MsgBeginRedelegate";
+
+/// Protobuf type url of standard Cosmos SDK distribution withdraw delegator reward message
+pub const COSMOS_SDK_WITHDRAW_DELEGATOR_REWARD_MSG_URL: &str =
+    "/cosmos.distribution.v1beta1.MsgWithdrawDelegatorReward";
+
+/// Protobuf type url of standard Cosmos SDK governance vote message
+pub const COSMOS_SDK_VOTE_MSG_URL: &str = "/cosmos.gov.v1beta1.MsgVote";
+
+/// Protobuf type url of standard IBC fungible token transfer message
+pub const IBC_TRANSFER_MSG_URL: &str = "/ibc.applications.transfer.v1.MsgTransfer";
+
 /// Storage prefix for account balances store
 /// <https://github.com/cosmos/cosmos-sdk/blob/35ae2c4c72d4aeb33447d5a7af23ca47f786606e/x/bank/types/key.go#L27>
 pub const BALANCES_PREFIX: u8 = 0x02;
@@ -81,6 +103,21 @@ pub const WASM_STORE_KEY: &str = "wasm";
 
 pub const RECIPIENT_FIELD: &str = "transfer.recipient";
 pub const HEIGHT_FIELD: &str = "tx.height";
+pub const SENDER_FIELD: &str = "transfer.sender";
+pub const AMOUNT_FIELD: &str = "transfer.amount";
+pub const MESSAGE_SENDER_FIELD: &str = "message.sender";
+pub const MESSAGE_MODULE_FIELD: &str = "message.module";
+pub const DELEGATE_VALIDATOR_FIELD: &str = "delegate.validator";
+pub const DELEGATE_AMOUNT_FIELD: &str = "delegate.amount";
+pub const IBC_TRANSFER_SENDER_FIELD: &str = "ibc_transfer.sender";
+pub const IBC_TRANSFER_RECEIVER_FIELD: &str = "ibc_transfer.receiver";
+
+/// Names of the Cosmos SDK modules as they are emitted in the `message.module` event attribute
+pub const BANK_MODULE_NAME: &str = "bank";
+pub const STAKING_MODULE_NAME: &str = "staking";
+pub const DISTRIBUTION_MODULE_NAME: &str = "distribution";
+pub const GOV_MODULE_NAME: &str = "governance";
+pub const IBC_TRANSFER_MODULE_NAME: &str = "transfer";
 
 impl KVReconstruct for Uint128 {
     fn reconstruct(storage_values: &[StorageValue]) -> NeutronResult<Uint128> {
@@ -95,6 +132,9 @@ impl KVReconstruct for Uint128 {
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 /// A structure that can be reconstructed from **StorageValues**'s for the **Balance Interchain Query**.
 /// Contains coins that are held by some account on remote chain.
+///
+/// Bank module does not store zero balances, such balances are reconstructed as zero coins with
+/// a denom from the storage key, so there is a coin for every requested denom.
 pub struct Balances {
     pub coins: Vec<Coin>,
 }
@@ -104,15 +144,30 @@ impl KVReconstruct for Balances {
         let mut coins: Vec<Coin> = Vec::with_capacity(storage_values.len());
 
         for kv in storage_values {
-            let balance: CosmosCoin = CosmosCoin::decode(kv.value.as_slice())?;
-            let amount = Uint128::from_str(balance.amount.as_str())?;
-            coins.push(Coin::new(amount.u128(), balance.denom));
+            let coin = match Coin::reconstruct_optional(kv)? {
+                Some(coin) => coin,
+                None => {
+                    let denom = get_balance_denom(&kv.key).ok_or_else(|| {
+                        NeutronError::InvalidQueryResultFormat("invalid balance key".into())
+                    })?;
+                    Coin::new(0, denom)
+                }
+            };
+            coins.push(coin);
         }
 
         Ok(Balances { coins })
     }
 }
 
+impl KVReconstructValue for Coin {
+    fn reconstruct_value(kv: &StorageValue) -> NeutronResult<Coin> {
+        let balance: CosmosCoin = CosmosCoin::decode(kv.value.as_slice())?;
+        let amount = Uint128::from_str(balance.amount.as_str())?;
+        Ok(Coin::new(amount.u128(), balance.denom))
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 /// A structure that can be reconstructed from **StorageValues**'s for the **Bank Total Interchain Query**.
 /// Contains total supply for specific denom that are held on remote chain.
@@ -210,6 +265,9 @@ pub struct Validator {
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 /// A structure that can be reconstructed from **StorageValues**'s for the **Staking Validator Interchain Query**.
 /// Contains validator info from remote chain.
+///
+/// Validators removed from remote chain are skipped, use `Vec<Option<Validator>>` reconstruction
+/// to match validators with the requested keys.
 pub struct StakingValidator {
     pub validators: Vec<Validator>,
 }
@@ -219,38 +277,44 @@ impl KVReconstruct for StakingValidator {
         let mut validators = Vec::with_capacity(storage_values.len());
 
         for kv in storage_values {
-            let validator: CosmosValidator = CosmosValidator::decode(kv.value.as_slice())?;
-            let description = &validator.description;
-            let commission = &validator.commission;
-
-            let validator = Validator {
-                operator_address: validator.operator_address,
-                delegator_shares: validator.delegator_shares,
-                jailed: validator.jailed,
-                status: validator.status,
-                tokens: validator.tokens,
-                unbonding_height: validator.unbonding_height as u64,
-                unbonding_time: validator.unbonding_time.map(|v| v.seconds as u64),
-                moniker: description.as_ref().map(|v| v.moniker.to_string()),
-                identity: description.as_ref().map(|v| v.identity.to_string()),
-                website: description.as_ref().map(|v| v.website.to_string()),
-                security_contact: description.as_ref().map(|v| v.security_contact.to_string()),
-                details: description.as_ref().map(|v| v.details.to_string()),
-                max_change_rate: get_max_change_rate(commission),
-                max_rate: get_max_rate(commission),
-                rate: get_rate(commission),
-                update_time: get_update_time(commission),
-                min_self_delegation: Decimal::from_str(validator.min_self_delegation.as_str())
-                    .unwrap_or_default(),
-            };
-
-            validators.push(validator)
+            if let Some(validator) = Validator::reconstruct_optional(kv)? {
+                validators.push(validator)
+            }
         }
 
         Ok(StakingValidator { validators })
     }
 }
 
+impl KVReconstructValue for Validator {
+    fn reconstruct_value(kv: &StorageValue) -> NeutronResult<Validator> {
+        let validator: CosmosValidator = CosmosValidator::decode(kv.value.as_slice())?;
+        let description = &validator.description;
+        let commission = &validator.commission;
+
+        Ok(Validator {
+            operator_address: validator.operator_address,
+            delegator_shares: validator.delegator_shares,
+            jailed: validator.jailed,
+            status: validator.status,
+            tokens: validator.tokens,
+            unbonding_height: validator.unbonding_height as u64,
+            unbonding_time: validator.unbonding_time.map(|v| v.seconds as u64),
+            moniker: description.as_ref().map(|v| v.moniker.to_string()),
+            identity: description.as_ref().map(|v| v.identity.to_string()),
+            website: description.as_ref().map(|v| v.website.to_string()),
+            security_contact: description.as_ref().map(|v| v.security_contact.to_string()),
+            details: description.as_ref().map(|v| v.details.to_string()),
+            max_change_rate: get_max_change_rate(commission),
+            max_rate: get_max_rate(commission),
+            rate: get_rate(commission),
+            update_time: get_update_time(commission),
+            min_self_delegation: Decimal::from_str(validator.min_self_delegation.as_str())
+                .unwrap_or_default(),
+        })
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 /// TallyResult defines a standard tally for a governance proposal.
 pub struct TallyResult {
@@ -277,6 +341,9 @@ pub struct Proposal {
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 /// A structure that can be reconstructed from **StorageValues**'s for the **Government Proposal Interchain Query**.
 /// Contains coins that are held by some account on remote chain.
+///
+/// Proposals removed from remote chain (e.g. the ones which did not reach min deposit) are skipped,
+/// use `Vec<Option<Proposal>>` reconstruction to match proposals with the requested keys.
 pub struct GovernmentProposal {
     pub proposals: Vec<Proposal>,
 }
@@ -286,44 +353,53 @@ impl KVReconstruct for GovernmentProposal {
         let mut proposals = Vec::with_capacity(storage_values.len());
 
         for kv in storage_values {
-            let proposal: CosmosProposal = CosmosProposal::decode(kv.value.as_slice())?;
+            if let Some(proposal) = Proposal::reconstruct_optional(kv)? {
+                proposals.push(proposal);
+            }
+        }
 
-            let mut coins: Vec<Coin> = Vec::with_capacity(proposal.total_deposit.len());
+        Ok(GovernmentProposal { proposals })
+    }
+}
 
-            for coin in proposal.total_deposit {
-                let amount = Uint128::from_str(coin.amount.as_str())?;
-                coins.push(Coin::new(amount.u128(), coin.denom));
-            }
+impl KVReconstructValue for Proposal {
+    fn reconstruct_value(kv: &StorageValue) -> NeutronResult<Proposal> {
+        let proposal: CosmosProposal = CosmosProposal::decode(kv.value.as_slice())?;
 
-            let final_tally_result = &proposal.final_tally_result;
-
-            let proposal = Proposal {
-                proposal_id: proposal.proposal_id,
-                proposal_type: proposal.content.map(|v| v.type_url),
-                total_deposit: coins,
-                status: proposal.status,
-                submit_time: proposal.submit_time.map(|v| v.seconds as u64),
-                deposit_end_time: proposal.deposit_end_time.map(|v| v.seconds as u64),
-                voting_end_time: proposal.voting_end_time.map(|v| v.seconds as u64),
-                voting_start_time: proposal.voting_start_time.map(|v| v.seconds as u64),
-                final_tally_result: final_tally_result.as_ref().map(|v| TallyResult {
-                    abstain: v.abstain.to_string(),
-                    no: v.no.to_string(),
-                    no_with_veto: v.no_with_veto.to_string(),
-                    yes: v.yes.to_string(),
-                }),
-            };
+        let mut coins: Vec<Coin> = Vec::with_capacity(proposal.total_deposit.len());
 
-            proposals.push(proposal);
+        for coin in proposal.total_deposit {
+            let amount = Uint128::from_str(coin.amount.as_str())?;
+            coins.push(Coin::new(amount.u128(), coin.denom));
         }
 
-        Ok(GovernmentProposal { proposals })
+        let final_tally_result = &proposal.final_tally_result;
+
+        Ok(Proposal {
+            proposal_id: proposal.proposal_id,
+            proposal_type: proposal.content.map(|v| v.type_url),
+            total_deposit: coins,
+            status: proposal.status,
+            submit_time: proposal.submit_time.map(|v| v.seconds as u64),
+            deposit_end_time: proposal.deposit_end_time.map(|v| v.seconds as u64),
+            voting_end_time: proposal.voting_end_time.map(|v| v.seconds as u64),
+            voting_start_time: proposal.voting_start_time.map(|v| v.seconds as u64),
+            final_tally_result: final_tally_result.as_ref().map(|v| TallyResult {
+                abstain: v.abstain.to_string(),
+                no: v.no.to_string(),
+                no_with_veto: v.no_with_veto.to_string(),
+                yes: v.yes.to_string(),
+            }),
+        })
     }
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 /// A structure that can be reconstructed from **StorageValues**'s for the **Delegator Delegation Interchain Query**.
 /// Contains delegations which some delegator has on remote chain.
+///
+/// Delegations removed from remote chain (i.e. fully undelegated) are skipped. A present delegation
+/// to a validator which is absent on remote chain is an invalid result.
 pub struct Delegations {
     pub delegations: Vec<cosmwasm_std::Delegation>,
 }
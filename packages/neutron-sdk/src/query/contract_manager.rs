@@ -0,0 +1,63 @@
+use crate::{
+    bindings::query::{NeutronQuery, PageRequest, PageResponse},
+    sudo::msg::SudoMsg,
+    NeutronResult,
+};
+use cosmwasm_std::{from_slice, Binary, Deps, StdError};
+use schemars::JsonSchema;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+/// Failure of a sudo call to a contract recorded by the contractmanager module
+pub struct Failure {
+    /// **address** is an address of the contract the sudo call failed for
+    pub address: String,
+    /// **id** is an identifier of the failure, unique per contract
+    pub id: u64,
+    /// **sudo_payload** is the JSON encoded sudo message of the failed call
+    #[serde(default)]
+    pub sudo_payload: Binary,
+    /// **error** is a (redacted) error of the failed call
+    #[serde(default)]
+    pub error: String,
+}
+
+impl Failure {
+    /// Deserializes the sudo message of the failed call, e.g. to handle it once again
+    pub fn sudo_msg(&self) -> NeutronResult<SudoMsg> {
+        self.sudo_payload()
+    }
+
+    /// Deserializes the sudo message of the failed call into a custom type, e.g. `TransferSudoMsg`
+    pub fn sudo_payload<T: DeserializeOwned>(&self) -> NeutronResult<T> {
+        if self.sudo_payload.is_empty() {
+            return Err(
+                StdError::generic_err(format!("failure {} has no sudo payload", self.id)).into(),
+            );
+        }
+        Ok(from_slice(self.sudo_payload.as_slice())?)
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct QueryFailuresResponse {
+    pub failures: Vec<Failure>,
+    #[serde(default)]
+    pub pagination: Option<PageResponse>,
+}
+
+/// Queries a single page of sudo call failures of the contract **address**.
+/// A failure can be resubmitted with `NeutronMsg::ResubmitFailure`.
+pub fn query_failures(
+    deps: Deps<NeutronQuery>,
+    address: impl Into<String>,
+    pagination: PageRequest,
+) -> NeutronResult<QueryFailuresResponse> {
+    let query = NeutronQuery::Failures {
+        address: address.into(),
+        pagination,
+    };
+    Ok(deps.querier.query(&query.into())?)
+}
@@ -0,0 +1,82 @@
+use crate::{
+    bindings::{
+        msg::MsgExecuteContract,
+        query::{NeutronQuery, PageRequest, PageResponse},
+    },
+    NeutronResult,
+};
+use cosmwasm_std::Deps;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Amount of schedules requested at once by [query_all_schedules]
+pub const SCHEDULES_PAGE_LIMIT: u64 = 100;
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+/// Schedule of the cron module, see `NeutronMsg::AddSchedule`
+pub struct Schedule {
+    /// **name** is a unique name of the schedule
+    pub name: String,
+    /// **period** is a period in blocks with which `msgs` are executed
+    pub period: u64,
+    /// **msgs** is a list of cosmwasm messages to be executed
+    pub msgs: Vec<MsgExecuteContract>,
+    /// **last_execute_height** is the last height the schedule was executed at
+    pub last_execute_height: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct QueryScheduleResponse {
+    pub schedule: Schedule,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct QuerySchedulesResponse {
+    pub schedules: Vec<Schedule>,
+    #[serde(default)]
+    pub pagination: Option<PageResponse>,
+}
+
+/// Queries the schedule with a given **name**
+pub fn query_schedule(
+    deps: Deps<NeutronQuery>,
+    name: impl Into<String>,
+) -> NeutronResult<QueryScheduleResponse> {
+    let query = NeutronQuery::Schedule { name: name.into() };
+    Ok(deps.querier.query(&query.into())?)
+}
+
+/// Queries a single page of schedules
+pub fn query_schedules(
+    deps: Deps<NeutronQuery>,
+    pagination: PageRequest,
+) -> NeutronResult<QuerySchedulesResponse> {
+    let query = NeutronQuery::Schedules { pagination };
+    Ok(deps.querier.query(&query.into())?)
+}
+
+/// Queries all the schedules of the cron module page by page, e.g. to reconcile the schedules
+/// a DAO wants to have with the ones the module actually holds
+pub fn query_all_schedules(deps: Deps<NeutronQuery>) -> NeutronResult<Vec<Schedule>> {
+    let mut schedules = vec![];
+    let mut pagination = PageRequest::new().with_limit(SCHEDULES_PAGE_LIMIT);
+    loop {
+        let res = query_schedules(deps, pagination)?;
+        schedules.extend(res.schedules);
+
+        match res.pagination {
+            Some(PageResponse {
+                next_key: Some(next_key),
+                ..
+            }) if !next_key.is_empty() => {
+                pagination = PageRequest::new()
+                    .with_key(next_key)
+                    .with_limit(SCHEDULES_PAGE_LIMIT)
+            }
+            _ => return Ok(schedules),
+        }
+    }
+}
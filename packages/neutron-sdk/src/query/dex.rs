@@ -0,0 +1,253 @@
+use crate::{
+    bindings::{
+        dex::{
+            msg::DexMsg,
+            query::{
+                AllLimitOrderTrancheResponse, AllPoolReservesResponse, AllTickLiquidityResponse,
+                AllUserDepositsResponse, AllUserLimitOrdersResponse, DexQuery,
+                EstimateMultiHopSwapResponse, EstimatePlaceLimitOrderResponse,
+                LimitOrderTrancheResponse, LimitOrderTrancheUserResponse, ParamsResponse,
+                PoolReservesResponse, PoolResponse,
+            },
+        },
+        query::{NeutronQuery, PageRequest},
+    },
+    NeutronResult,
+};
+use cosmwasm_std::{Deps, StdError};
+
+/// Builds DEX pair identifier out of two denoms, e.g. `untrn<>uatom`. The denoms are sorted.
+pub fn pair_id(token_a: &str, token_b: &str) -> String {
+    if token_a <= token_b {
+        format!("{}<>{}", token_a, token_b)
+    } else {
+        format!("{}<>{}", token_b, token_a)
+    }
+}
+
+pub fn query_dex_params(deps: Deps<NeutronQuery>) -> NeutronResult<ParamsResponse> {
+    query_dex(deps, DexQuery::Params {})
+}
+
+pub fn query_pool(
+    deps: Deps<NeutronQuery>,
+    pair_id: impl Into<String>,
+    tick_index: i64,
+    fee: u64,
+) -> NeutronResult<PoolResponse> {
+    query_dex(
+        deps,
+        DexQuery::Pool {
+            pair_id: pair_id.into(),
+            tick_index,
+            fee,
+        },
+    )
+}
+
+pub fn query_pool_by_id(deps: Deps<NeutronQuery>, pool_id: u64) -> NeutronResult<PoolResponse> {
+    query_dex(deps, DexQuery::PoolByID { pool_id })
+}
+
+pub fn query_pool_reserves(
+    deps: Deps<NeutronQuery>,
+    pair_id: impl Into<String>,
+    token_in: impl Into<String>,
+    tick_index: i64,
+    fee: u64,
+) -> NeutronResult<PoolReservesResponse> {
+    query_dex(
+        deps,
+        DexQuery::PoolReserves {
+            pair_id: pair_id.into(),
+            token_in: token_in.into(),
+            tick_index,
+            fee,
+        },
+    )
+}
+
+pub fn query_all_pool_reserves(
+    deps: Deps<NeutronQuery>,
+    pair_id: impl Into<String>,
+    token_in: impl Into<String>,
+    pagination: Option<PageRequest>,
+) -> NeutronResult<AllPoolReservesResponse> {
+    query_dex(
+        deps,
+        DexQuery::PoolReservesAll {
+            pair_id: pair_id.into(),
+            token_in: token_in.into(),
+            pagination,
+        },
+    )
+}
+
+pub fn query_all_tick_liquidity(
+    deps: Deps<NeutronQuery>,
+    pair_id: impl Into<String>,
+    token_in: impl Into<String>,
+    pagination: Option<PageRequest>,
+) -> NeutronResult<AllTickLiquidityResponse> {
+    query_dex(
+        deps,
+        DexQuery::TickLiquidityAll {
+            pair_id: pair_id.into(),
+            token_in: token_in.into(),
+            pagination,
+        },
+    )
+}
+
+pub fn query_limit_order_tranche(
+    deps: Deps<NeutronQuery>,
+    pair_id: impl Into<String>,
+    tick_index: i64,
+    token_in: impl Into<String>,
+    tranche_key: impl Into<String>,
+) -> NeutronResult<LimitOrderTrancheResponse> {
+    query_dex(
+        deps,
+        DexQuery::LimitOrderTranche {
+            pair_id: pair_id.into(),
+            tick_index,
+            token_in: token_in.into(),
+            tranche_key: tranche_key.into(),
+        },
+    )
+}
+
+pub fn query_all_limit_order_tranches(
+    deps: Deps<NeutronQuery>,
+    pair_id: impl Into<String>,
+    token_in: impl Into<String>,
+    pagination: Option<PageRequest>,
+) -> NeutronResult<AllLimitOrderTrancheResponse> {
+    query_dex(
+        deps,
+        DexQuery::LimitOrderTrancheAll {
+            pair_id: pair_id.into(),
+            token_in: token_in.into(),
+            pagination,
+        },
+    )
+}
+
+pub fn query_user_limit_order(
+    deps: Deps<NeutronQuery>,
+    address: impl Into<String>,
+    tranche_key: impl Into<String>,
+) -> NeutronResult<LimitOrderTrancheUserResponse> {
+    query_dex(
+        deps,
+        DexQuery::LimitOrderTrancheUser {
+            address: address.into(),
+            tranche_key: tranche_key.into(),
+        },
+    )
+}
+
+pub fn query_all_user_limit_orders(
+    deps: Deps<NeutronQuery>,
+    address: impl Into<String>,
+    pagination: Option<PageRequest>,
+) -> NeutronResult<AllUserLimitOrdersResponse> {
+    query_dex(
+        deps,
+        DexQuery::LimitOrderTrancheUserAllByAddress {
+            address: address.into(),
+            pagination,
+        },
+    )
+}
+
+pub fn query_all_user_deposits(
+    deps: Deps<NeutronQuery>,
+    address: impl Into<String>,
+    include_pool_data: bool,
+    pagination: Option<PageRequest>,
+) -> NeutronResult<AllUserDepositsResponse> {
+    query_dex(
+        deps,
+        DexQuery::UserDepositAll {
+            address: address.into(),
+            include_pool_data,
+            pagination,
+        },
+    )
+}
+
+/// Estimates the result of `DexMsg::MultiHopSwap` **msg** sent by **creator**
+pub fn query_estimate_multi_hop_swap(
+    deps: Deps<NeutronQuery>,
+    creator: impl Into<String>,
+    msg: DexMsg,
+) -> NeutronResult<EstimateMultiHopSwapResponse> {
+    if !matches!(msg, DexMsg::MultiHopSwap { .. }) {
+        return Err(StdError::generic_err("only MultiHopSwap message can be estimated").into());
+    }
+    query_dex(deps, estimate_query(creator.into(), msg)?)
+}
+
+/// Estimates the result of `DexMsg::PlaceLimitOrder` **msg** sent by **creator**
+pub fn query_estimate_place_limit_order(
+    deps: Deps<NeutronQuery>,
+    creator: impl Into<String>,
+    msg: DexMsg,
+) -> NeutronResult<EstimatePlaceLimitOrderResponse> {
+    if !matches!(msg, DexMsg::PlaceLimitOrder { .. }) {
+        return Err(StdError::generic_err("only PlaceLimitOrder message can be estimated").into());
+    }
+    query_dex(deps, estimate_query(creator.into(), msg)?)
+}
+
+/// Converts a swap **msg** into a query which estimates its result
+pub fn estimate_query(creator: String, msg: DexMsg) -> NeutronResult<DexQuery> {
+    match msg {
+        DexMsg::MultiHopSwap {
+            receiver,
+            routes,
+            amount_in,
+            exit_limit_price,
+            pick_best_route,
+        } => Ok(DexQuery::EstimateMultiHopSwap {
+            creator,
+            receiver,
+            routes,
+            amount_in,
+            exit_limit_price,
+            pick_best_route,
+        }),
+        DexMsg::PlaceLimitOrder {
+            receiver,
+            token_in,
+            token_out,
+            tick_index_in_to_out,
+            amount_in,
+            order_type,
+            expiration_time,
+            max_amount_out,
+        } => Ok(DexQuery::EstimatePlaceLimitOrder {
+            creator,
+            receiver,
+            token_in,
+            token_out,
+            tick_index_in_to_out,
+            amount_in,
+            order_type,
+            expiration_time,
+            max_amount_out,
+        }),
+        _ => Err(StdError::generic_err(
+            "only MultiHopSwap and PlaceLimitOrder messages can be estimated",
+        )
+        .into()),
+    }
+}
+
+fn query_dex<T: serde::de::DeserializeOwned>(
+    deps: Deps<NeutronQuery>,
+    query: DexQuery,
+) -> NeutronResult<T> {
+    Ok(deps.querier.query(&NeutronQuery::Dex(query).into())?)
+}
@@ -0,0 +1,58 @@
+use crate::{
+    bindings::query::NeutronQuery, query::total_burned_neutrons::query_total_burned_neutrons,
+    NeutronResult,
+};
+use cosmwasm_std::{Coin, Deps};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct FeeburnerParams {
+    /// **neutron_denom** is a denom of the Neutron token, fees in this denom are burned
+    pub neutron_denom: String,
+    /// **reserve_address** is an address of the reserve, deprecated and empty on newer Neutron versions
+    #[serde(default)]
+    pub reserve_address: String,
+    /// **treasury_address** is an address of the treasury all the fees in other denoms are sent to
+    pub treasury_address: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct FeeburnerParamsResponse {
+    pub params: FeeburnerParams,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+/// TreasuryStats describes the flows of Neutron tokens handled by the feeburner module
+pub struct TreasuryStats {
+    /// **treasury_address** is an address of the treasury
+    pub treasury_address: String,
+    /// **treasury_balance** is a balance of Neutron tokens of the treasury
+    pub treasury_balance: Coin,
+    /// **total_burned** is a total amount of burned Neutron tokens
+    pub total_burned: Coin,
+}
+
+/// Returns parameters of the feeburner module
+pub fn query_feeburner_params(deps: Deps<NeutronQuery>) -> NeutronResult<FeeburnerParamsResponse> {
+    let query = NeutronQuery::FeeburnerParams {};
+    Ok(deps.querier.query(&query.into())?)
+}
+
+/// Returns the treasury address along with its balance of Neutron tokens and
+/// the total amount of burned Neutron tokens
+pub fn query_treasury_stats(deps: Deps<NeutronQuery>) -> NeutronResult<TreasuryStats> {
+    let params = query_feeburner_params(deps)?.params;
+    let treasury_balance = deps
+        .querier
+        .query_balance(&params.treasury_address, params.neutron_denom)?;
+    let total_burned = query_total_burned_neutrons(deps)?.coin;
+    Ok(TreasuryStats {
+        treasury_address: params.treasury_address,
+        treasury_balance,
+        total_burned,
+    })
+}
@@ -0,0 +1,50 @@
+use crate::{
+    bindings::{
+        msg::{ChannelOrdering, NeutronMsg},
+        query::NeutronQuery,
+    },
+    NeutronResult,
+};
+use cosmwasm_std::{Coin, Deps};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct InterchainTxsParams {
+    /// **msg_submit_tx_max_messages** is a maximum number of messages in a single interchain transaction
+    pub msg_submit_tx_max_messages: u64,
+    /// **register_fee** is a fee charged for an interchain account registration
+    #[serde(default)]
+    pub register_fee: Vec<Coin>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct InterchainTxsParamsResponse {
+    pub params: InterchainTxsParams,
+}
+
+pub fn query_interchain_txs_params(
+    deps: Deps<NeutronQuery>,
+) -> NeutronResult<InterchainTxsParamsResponse> {
+    let query = NeutronQuery::InterchainTxsParams {};
+    Ok(deps.querier.query(&query.into())?)
+}
+
+/// Builds a register interchain account message with the registration fee taken from
+/// the interchaintxs module params. The fee is omitted if the module doesn't charge it.
+pub fn register_interchain_account_with_fee(
+    deps: Deps<NeutronQuery>,
+    connection_id: impl Into<String>,
+    interchain_account_id: impl Into<String>,
+    ordering: Option<ChannelOrdering>,
+) -> NeutronResult<NeutronMsg> {
+    let register_fee = query_interchain_txs_params(deps)?.params.register_fee;
+    Ok(NeutronMsg::register_interchain_account_with_options(
+        connection_id.into(),
+        interchain_account_id.into(),
+        Some(register_fee).filter(|fee| !fee.is_empty()),
+        ordering,
+    ))
+}
@@ -16,3 +16,23 @@ pub fn query_min_ibc_fee(deps: Deps<NeutronQuery>) -> NeutronResult<MinIbcFeeRes
     let query = NeutronQuery::MinIbcFee {};
     Ok(deps.querier.query(&query.into())?)
 }
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct FeeRefunderParams {
+    /// **min_fee** is a minimum fee required to be attached to IBC messages
+    pub min_fee: IbcFee,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct FeeRefunderParamsResponse {
+    pub params: FeeRefunderParams,
+}
+
+pub fn query_fee_refunder_params(
+    deps: Deps<NeutronQuery>,
+) -> NeutronResult<FeeRefunderParamsResponse> {
+    let query = NeutronQuery::FeeRefunderParams {};
+    Ok(deps.querier.query(&query.into())?)
+}
@@ -1,3 +1,11 @@
+pub mod contract_manager;
+pub mod cron;
+pub mod dex;
+pub mod feeburner;
+pub mod interchain_txs;
 pub mod min_ibc_fee;
 pub mod token_factory;
 pub mod total_burned_neutrons;
+
+#[cfg(test)]
+mod testing;
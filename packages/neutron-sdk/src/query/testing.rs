@@ -0,0 +1,507 @@
+use crate::bindings::msg::{ChannelOrdering, IbcFee, MsgExecuteContract, NeutronMsg};
+use crate::bindings::query::{NeutronQuery, PageRequest, PageResponse};
+use crate::query::contract_manager::{query_failures, Failure, QueryFailuresResponse};
+use crate::query::cron::{
+    query_all_schedules, query_schedule, QueryScheduleResponse, QuerySchedulesResponse, Schedule,
+    SCHEDULES_PAGE_LIMIT,
+};
+use crate::query::feeburner::{
+    query_feeburner_params, query_treasury_stats, FeeburnerParams, TreasuryStats,
+};
+use crate::query::interchain_txs::{
+    query_interchain_txs_params, register_interchain_account_with_fee, InterchainTxsParams,
+    InterchainTxsParamsResponse,
+};
+use crate::query::min_ibc_fee::{query_fee_refunder_params, FeeRefunderParams};
+use crate::query::token_factory::{
+    build_factory_denom, parse_factory_denom, query_before_send_hook, query_token_factory_params,
+    BeforeSendHookResponse, FactoryDenom, TokenFactoryParams,
+};
+use crate::query::total_burned_neutrons::TotalBurnedNeutronsAmountResponse;
+use crate::sudo::msg::{RequestPacket, SudoMsg};
+use crate::NeutronError;
+use cosmwasm_std::testing::{MockApi, MockQuerier, MockStorage};
+use cosmwasm_std::{
+    coin, from_slice, to_binary, to_vec, Binary, Coin, ContractResult, DenomMetadata, DenomUnit,
+    OwnedDeps, StdError, SystemResult, Uint128,
+};
+use std::marker::PhantomData;
+
+fn mock_dependencies(
+    handler: impl Fn(&NeutronQuery) -> Binary + 'static,
+) -> OwnedDeps<MockStorage, MockApi, MockQuerier<NeutronQuery>, NeutronQuery> {
+    mock_dependencies_with_balances(&[], handler)
+}
+
+fn mock_dependencies_with_balances(
+    balances: &[(&str, &[Coin])],
+    handler: impl Fn(&NeutronQuery) -> Binary + 'static,
+) -> OwnedDeps<MockStorage, MockApi, MockQuerier<NeutronQuery>, NeutronQuery> {
+    let querier = MockQuerier::<NeutronQuery>::new(balances)
+        .with_custom_handler(move |query| SystemResult::Ok(ContractResult::Ok(handler(query))));
+
+    OwnedDeps {
+        storage: MockStorage::default(),
+        api: MockApi::default(),
+        querier,
+        custom_query_type: PhantomData,
+    }
+}
+
+fn build_schedule(i: u8) -> Schedule {
+    Schedule {
+        name: format!("schedule_{}", i),
+        period: 10 * i as u64,
+        msgs: vec![MsgExecuteContract {
+            contract: "contract".to_string(),
+            msg: format!("{{\"tick\":{{\"i\":{}}}}}", i),
+        }],
+        last_execute_height: 100 + i as u64,
+    }
+}
+
+#[test]
+fn test_query_schedules() {
+    // 5 schedules split into pages of 2 schedules, next key is a number of the next schedule
+    let deps = mock_dependencies(|query| match query {
+        NeutronQuery::Schedule { name } => {
+            assert_eq!(name, "schedule_1");
+            to_binary(&QueryScheduleResponse {
+                schedule: build_schedule(1),
+            })
+            .unwrap()
+        }
+        NeutronQuery::Schedules { pagination } => {
+            assert_eq!(pagination.limit, SCHEDULES_PAGE_LIMIT);
+            let start = pagination.key.first().copied().unwrap_or(0);
+            let end = (start + 2).min(5);
+            to_binary(&QuerySchedulesResponse {
+                schedules: (start..end).map(build_schedule).collect(),
+                pagination: Some(PageResponse {
+                    next_key: (end < 5).then(|| Binary::from(vec![end])),
+                    total: 0,
+                }),
+            })
+            .unwrap()
+        }
+        _ => unimplemented!(),
+    });
+
+    assert_eq!(
+        query_schedule(deps.as_ref(), "schedule_1")
+            .unwrap()
+            .schedule,
+        build_schedule(1)
+    );
+    assert_eq!(
+        query_all_schedules(deps.as_ref()).unwrap(),
+        (0..5).map(build_schedule).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn test_query_failures() {
+    let sudo_msg = SudoMsg::Timeout {
+        request: RequestPacket {
+            sequence: Some(1),
+            source_port: Some("transfer".to_string()),
+            source_channel: Some("channel-0".to_string()),
+            destination_port: None,
+            destination_channel: None,
+            data: None,
+            timeout_height: None,
+            timeout_timestamp: None,
+        },
+    };
+    let failures = vec![
+        Failure {
+            address: "contract".to_string(),
+            id: 0,
+            sudo_payload: to_binary(&sudo_msg).unwrap(),
+            error: "codespace: wasm, code: 5".to_string(),
+        },
+        Failure {
+            address: "contract".to_string(),
+            id: 1,
+            sudo_payload: Binary::default(),
+            error: String::new(),
+        },
+    ];
+    let response = QueryFailuresResponse {
+        failures: failures.clone(),
+        pagination: None,
+    };
+    let deps = mock_dependencies(move |query| match query {
+        NeutronQuery::Failures {
+            address,
+            pagination,
+        } => {
+            assert_eq!(address, "contract");
+            assert_eq!(pagination.limit, 10);
+            to_binary(&response).unwrap()
+        }
+        _ => unimplemented!(),
+    });
+
+    let res = query_failures(deps.as_ref(), "contract", PageRequest::new().with_limit(10)).unwrap();
+    assert_eq!(res.failures, failures);
+    assert_eq!(res.failures[0].sudo_msg().unwrap(), sudo_msg);
+    assert_eq!(
+        res.failures[1].sudo_msg().unwrap_err(),
+        NeutronError::Std(StdError::generic_err("failure 1 has no sudo payload"))
+    );
+
+    // older failures have no payload at all
+    let failure: Failure = from_slice(br#"{"address":"contract","id":2}"#).unwrap();
+    assert!(failure.sudo_payload.is_empty());
+
+    assert_eq!(
+        to_vec(&NeutronMsg::resubmit_failure(0)).unwrap(),
+        br#"{"resubmit_failure":{"failure_id":0}}"#.to_vec()
+    );
+}
+
+#[test]
+fn test_token_factory_queries() {
+    let deps = mock_dependencies(|query| match query {
+        NeutronQuery::BeforeSendHook { denom } => {
+            assert_eq!(denom, "factory/contract/token");
+            to_binary(&BeforeSendHookResponse {
+                contract_addr: "hook".to_string(),
+            })
+            .unwrap()
+        }
+        // older Neutron versions return only some of the params
+        NeutronQuery::TokenFactoryParams {} => Binary::from(
+            br#"{"params":{"denom_creation_fee":[{"denom":"untrn","amount":"100"}]}}"#.as_slice(),
+        ),
+        _ => unimplemented!(),
+    });
+
+    assert_eq!(
+        query_before_send_hook(deps.as_ref(), "factory/contract/token")
+            .unwrap()
+            .contract_addr,
+        "hook"
+    );
+    assert_eq!(
+        query_token_factory_params(deps.as_ref()).unwrap().params,
+        TokenFactoryParams {
+            denom_creation_fee: vec![coin(100, "untrn")],
+            denom_creation_gas_consume: 0,
+            fee_collector_address: String::new(),
+            whitelisted_hooks: vec![],
+        }
+    );
+}
+
+#[cfg(feature = "cosmwasm_1_1")]
+#[test]
+fn test_query_factory_denoms_info() {
+    use crate::query::token_factory::{
+        query_factory_denoms_info, DenomAdminResponse, DenomsFromCreatorResponse, FactoryDenomInfo,
+    };
+
+    let deps = mock_dependencies_with_balances(
+        &[
+            ("alice", &[coin(10, "factory/contract/a"), coin(5, "untrn")]),
+            ("bob", &[coin(20, "factory/contract/a")]),
+        ],
+        |query| match query {
+            NeutronQuery::DenomsFromCreator { creator } => {
+                assert_eq!(creator, "contract");
+                to_binary(&DenomsFromCreatorResponse {
+                    denoms: vec![
+                        "factory/contract/a".to_string(),
+                        "factory/contract/b".to_string(),
+                    ],
+                })
+                .unwrap()
+            }
+            NeutronQuery::DenomAdmin { subdenom } => to_binary(&DenomAdminResponse {
+                admin: match subdenom.as_str() {
+                    "factory/contract/a" => "contract".to_string(),
+                    _ => String::new(),
+                },
+            })
+            .unwrap(),
+            _ => unimplemented!(),
+        },
+    );
+
+    assert_eq!(
+        query_factory_denoms_info(deps.as_ref(), "contract").unwrap(),
+        vec![
+            FactoryDenomInfo {
+                denom: "factory/contract/a".to_string(),
+                supply: coin(30, "factory/contract/a"),
+                admin: "contract".to_string(),
+            },
+            FactoryDenomInfo {
+                denom: "factory/contract/b".to_string(),
+                supply: coin(0, "factory/contract/b"),
+                admin: String::new(),
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_factory_denom() {
+    struct TestCase {
+        denom: &'static str,
+        expected: Result<(&'static str, &'static str), ()>,
+    }
+    let test_cases = vec![
+        TestCase {
+            denom: "factory/neutron1creator/utoken",
+            expected: Ok(("neutron1creator", "utoken")),
+        },
+        TestCase {
+            denom: "factory/neutron1creator/lp/untrn/uatom",
+            expected: Ok(("neutron1creator", "lp/untrn/uatom")),
+        },
+        TestCase {
+            denom: "factory/neutron1creator/",
+            expected: Ok(("neutron1creator", "")),
+        },
+        TestCase {
+            denom: "factory/neutron1creator",
+            expected: Err(()),
+        },
+        TestCase {
+            denom: "factory//utoken",
+            expected: Err(()),
+        },
+        TestCase {
+            denom: "ibc/neutron1creator/utoken",
+            expected: Err(()),
+        },
+        TestCase {
+            denom: "factoryx/neutron1creator/utoken",
+            expected: Err(()),
+        },
+        TestCase {
+            denom: "untrn",
+            expected: Err(()),
+        },
+    ];
+
+    for tc in test_cases {
+        match tc.expected {
+            Ok((creator, subdenom)) => {
+                assert_eq!(
+                    parse_factory_denom(tc.denom).unwrap(),
+                    (creator.to_string(), subdenom.to_string()),
+                    "denom: {}",
+                    tc.denom
+                );
+                assert_eq!(build_factory_denom(creator, subdenom).unwrap(), tc.denom);
+            }
+            Err(()) => assert!(
+                matches!(
+                    parse_factory_denom(tc.denom),
+                    Err(NeutronError::InvalidFactoryDenom(_))
+                ),
+                "denom: {}",
+                tc.denom
+            ),
+        }
+    }
+
+    assert!(build_factory_denom("a".repeat(76), "utoken").is_err());
+    assert!(build_factory_denom("neutron1creator", "a".repeat(45)).is_err());
+    assert_eq!(
+        FactoryDenom::new("neutron1creator", "a".repeat(44))
+            .unwrap()
+            .to_string(),
+        format!("factory/neutron1creator/{}", "a".repeat(44))
+    );
+}
+
+#[test]
+fn test_token_factory_msgs() {
+    let metadata = DenomMetadata {
+        description: "Token".to_string(),
+        denom_units: vec![
+            DenomUnit {
+                denom: "factory/contract/utoken".to_string(),
+                exponent: 0,
+                aliases: vec![],
+            },
+            DenomUnit {
+                denom: "token".to_string(),
+                exponent: 6,
+                aliases: vec![],
+            },
+        ],
+        base: "factory/contract/utoken".to_string(),
+        display: "token".to_string(),
+        name: "Token".to_string(),
+        symbol: "TKN".to_string(),
+        uri: String::new(),
+        uri_hash: String::new(),
+    };
+    assert_eq!(
+        NeutronMsg::submit_set_denom_metadata(metadata.clone()),
+        NeutronMsg::SetDenomMetadata {
+            description: metadata.description,
+            denom_units: metadata.denom_units,
+            base: metadata.base,
+            display: metadata.display,
+            name: metadata.name,
+            symbol: metadata.symbol,
+            uri: metadata.uri,
+            uri_hash: metadata.uri_hash,
+        }
+    );
+
+    assert_eq!(
+        to_vec(&NeutronMsg::submit_burn_tokens_from(
+            "factory/contract/utoken",
+            Uint128::new(10),
+            "holder"
+        ))
+        .unwrap(),
+        br#"{"burn_tokens":{"denom":"factory/contract/utoken","amount":"10","burn_from_address":"holder"}}"#.to_vec()
+    );
+    assert_eq!(
+        to_vec(&NeutronMsg::submit_force_transfer(
+            "factory/contract/utoken",
+            Uint128::new(10),
+            "from",
+            "to"
+        ))
+        .unwrap(),
+        br#"{"force_transfer":{"denom":"factory/contract/utoken","amount":"10","transfer_from_address":"from","transfer_to_address":"to"}}"#.to_vec()
+    );
+    assert_eq!(
+        to_vec(&NeutronMsg::submit_set_before_send_hook(
+            "factory/contract/utoken",
+            "hook"
+        ))
+        .unwrap(),
+        br#"{"set_before_send_hook":{"denom":"factory/contract/utoken","contract_addr":"hook"}}"#
+            .to_vec()
+    );
+}
+
+#[test]
+fn test_params_queries() {
+    let deps = mock_dependencies(|query| {
+        match query {
+        NeutronQuery::InterchainTxsParams {} => Binary::from(
+            br#"{"params":{"msg_submit_tx_max_messages":16,"register_fee":[{"denom":"untrn","amount":"1000000"}]}}"#
+                .as_slice(),
+        ),
+        NeutronQuery::FeeRefunderParams {} => Binary::from(
+            br#"{"params":{"min_fee":{"recv_fee":[],"ack_fee":[{"denom":"untrn","amount":"1000"}],"timeout_fee":[{"denom":"untrn","amount":"1000"}]}}}"#
+                .as_slice(),
+        ),
+        _ => unimplemented!(),
+    }
+    });
+
+    assert_eq!(
+        query_interchain_txs_params(deps.as_ref()).unwrap().params,
+        InterchainTxsParams {
+            msg_submit_tx_max_messages: 16,
+            register_fee: vec![coin(1_000_000, "untrn")],
+        }
+    );
+    assert_eq!(
+        query_fee_refunder_params(deps.as_ref()).unwrap().params,
+        FeeRefunderParams {
+            min_fee: IbcFee {
+                recv_fee: vec![],
+                ack_fee: vec![coin(1000, "untrn")],
+                timeout_fee: vec![coin(1000, "untrn")],
+            },
+        }
+    );
+}
+
+#[test]
+fn test_register_interchain_account_with_fee() {
+    struct TestCase {
+        register_fee: Vec<Coin>,
+        expected_fee: Option<Vec<Coin>>,
+    }
+    let test_cases = vec![
+        TestCase {
+            register_fee: vec![coin(1_000_000, "untrn")],
+            expected_fee: Some(vec![coin(1_000_000, "untrn")]),
+        },
+        TestCase {
+            register_fee: vec![],
+            expected_fee: None,
+        },
+    ];
+
+    for tc in test_cases {
+        let register_fee = tc.register_fee.clone();
+        let deps = mock_dependencies(move |query| match query {
+            NeutronQuery::InterchainTxsParams {} => to_binary(&InterchainTxsParamsResponse {
+                params: InterchainTxsParams {
+                    msg_submit_tx_max_messages: 16,
+                    register_fee: register_fee.clone(),
+                },
+            })
+            .unwrap(),
+            _ => unimplemented!(),
+        });
+
+        assert_eq!(
+            register_interchain_account_with_fee(
+                deps.as_ref(),
+                "connection-0",
+                "ica",
+                Some(ChannelOrdering::OrderOrdered)
+            )
+            .unwrap(),
+            NeutronMsg::RegisterInterchainAccount {
+                connection_id: "connection-0".to_string(),
+                interchain_account_id: "ica".to_string(),
+                register_fee: tc.expected_fee,
+                ordering: Some(ChannelOrdering::OrderOrdered),
+            }
+        );
+    }
+}
+
+#[test]
+fn test_feeburner_queries() {
+    let deps = mock_dependencies_with_balances(
+        &[("treasury", &[coin(500, "untrn"), coin(7, "uatom")])],
+        |query| match query {
+            // newer Neutron versions don't return the deprecated reserve address
+            NeutronQuery::FeeburnerParams {} => Binary::from(
+                br#"{"params":{"neutron_denom":"untrn","treasury_address":"treasury"}}"#.as_slice(),
+            ),
+            NeutronQuery::TotalBurnedNeutronsAmount {} => {
+                to_binary(&TotalBurnedNeutronsAmountResponse {
+                    coin: coin(1000, "untrn"),
+                })
+                .unwrap()
+            }
+            _ => unimplemented!(),
+        },
+    );
+
+    assert_eq!(
+        query_feeburner_params(deps.as_ref()).unwrap().params,
+        FeeburnerParams {
+            neutron_denom: "untrn".to_string(),
+            reserve_address: String::new(),
+            treasury_address: "treasury".to_string(),
+        }
+    );
+    assert_eq!(
+        query_treasury_stats(deps.as_ref()).unwrap(),
+        TreasuryStats {
+            treasury_address: "treasury".to_string(),
+            treasury_balance: coin(500, "untrn"),
+            total_burned: coin(1000, "untrn"),
+        }
+    );
+}
@@ -1,7 +1,103 @@
-use crate::{bindings::query::NeutronQuery, NeutronResult};
-use cosmwasm_std::Deps;
+use crate::{bindings::query::NeutronQuery, NeutronError, NeutronResult};
+use cosmwasm_std::{Coin, Deps};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// Prefix of all the denoms created by the TokenFactory module
+pub const FACTORY_DENOM_PREFIX: &str = "factory";
+/// Maximum length of a denom creator address
+pub const FACTORY_DENOM_MAX_CREATOR_LEN: usize = 75;
+/// Maximum length of a subdenom
+pub const FACTORY_DENOM_MAX_SUBDENOM_LEN: usize = 44;
+
+/// FactoryDenom is a denom created by the TokenFactory module: `factory/{creator}/{subdenom}`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FactoryDenom {
+    /// **creator** is an address of the denom creator
+    pub creator: String,
+    /// **subdenom** is a name of the denom chosen by the creator, may contain `/`
+    pub subdenom: String,
+}
+
+impl FactoryDenom {
+    /// Builds a factory denom the same way the module does, without a chain round-trip
+    pub fn new(creator: impl Into<String>, subdenom: impl Into<String>) -> NeutronResult<Self> {
+        let denom = FactoryDenom {
+            creator: creator.into(),
+            subdenom: subdenom.into(),
+        };
+        denom.validate()?;
+        Ok(denom)
+    }
+
+    fn validate(&self) -> NeutronResult<()> {
+        if self.creator.is_empty() || self.creator.contains('/') {
+            return Err(NeutronError::InvalidFactoryDenom(format!(
+                "invalid creator: {:?}",
+                self.creator
+            )));
+        }
+        if self.creator.len() > FACTORY_DENOM_MAX_CREATOR_LEN {
+            return Err(NeutronError::InvalidFactoryDenom(format!(
+                "creator is too long: {} > {}",
+                self.creator.len(),
+                FACTORY_DENOM_MAX_CREATOR_LEN
+            )));
+        }
+        if self.subdenom.len() > FACTORY_DENOM_MAX_SUBDENOM_LEN {
+            return Err(NeutronError::InvalidFactoryDenom(format!(
+                "subdenom is too long: {} > {}",
+                self.subdenom.len(),
+                FACTORY_DENOM_MAX_SUBDENOM_LEN
+            )));
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for FactoryDenom {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}/{}/{}",
+            FACTORY_DENOM_PREFIX, self.creator, self.subdenom
+        )
+    }
+}
+
+impl FromStr for FactoryDenom {
+    type Err = NeutronError;
+
+    fn from_str(denom: &str) -> Result<Self, Self::Err> {
+        let (creator, subdenom) = denom
+            .strip_prefix(FACTORY_DENOM_PREFIX)
+            .and_then(|rest| rest.strip_prefix('/'))
+            .and_then(|rest| rest.split_once('/'))
+            .ok_or_else(|| {
+                NeutronError::InvalidFactoryDenom(format!(
+                    "{:?} is not in the {}/{{creator}}/{{subdenom}} format",
+                    denom, FACTORY_DENOM_PREFIX
+                ))
+            })?;
+        FactoryDenom::new(creator, subdenom)
+    }
+}
+
+/// Builds a `factory/{creator}/{subdenom}` denom
+pub fn build_factory_denom(
+    creator: impl Into<String>,
+    subdenom: impl Into<String>,
+) -> NeutronResult<String> {
+    Ok(FactoryDenom::new(creator, subdenom)?.to_string())
+}
+
+/// Parses a `factory/{creator}/{subdenom}` denom into the creator and subdenom
+pub fn parse_factory_denom(denom: &str) -> NeutronResult<(String, String)> {
+    let FactoryDenom { creator, subdenom } = denom.parse()?;
+    Ok((creator, subdenom))
+}
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
@@ -15,6 +111,66 @@ pub struct DenomAdminResponse {
     pub admin: String,
 }
 
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct DenomsFromCreatorResponse {
+    #[serde(default)]
+    pub denoms: Vec<String>,
+}
+
+#[cfg(feature = "cosmwasm_1_1")]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+/// FactoryDenomInfo describes a denom created by the TokenFactory module
+pub struct FactoryDenomInfo {
+    /// **denom** is a full denom, e.g. `factory/{creator}/{subdenom}`
+    pub denom: String,
+    /// **supply** is a total supply of the denom
+    pub supply: Coin,
+    /// **admin** is an address of the denom admin, empty if the admin is renounced
+    pub admin: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct BeforeSendHookResponse {
+    /// **contract_addr** is an address of the hook contract, empty if the denom has no hook
+    pub contract_addr: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+/// WhitelistedHook describes contracts allowed to be set as before send hooks
+pub struct WhitelistedHook {
+    /// **code_id** is a code id of the hook contract
+    pub code_id: u64,
+    /// **denom_creator** is an address of the denom creator allowed to use the hook
+    pub denom_creator: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct TokenFactoryParams {
+    /// **denom_creation_fee** is a fee charged for a denom creation
+    #[serde(default)]
+    pub denom_creation_fee: Vec<Coin>,
+    /// **denom_creation_gas_consume** is an amount of gas consumed by a denom creation
+    #[serde(default)]
+    pub denom_creation_gas_consume: u64,
+    /// **fee_collector_address** is an address the denom creation fee is sent to
+    #[serde(default)]
+    pub fee_collector_address: String,
+    /// **whitelisted_hooks** is a list of contracts allowed to be set as before send hooks
+    #[serde(default)]
+    pub whitelisted_hooks: Vec<WhitelistedHook>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct TokenFactoryParamsResponse {
+    pub params: TokenFactoryParams,
+}
+
 pub fn query_full_denom(
     deps: Deps<NeutronQuery>,
     creator_addr: impl Into<String>,
@@ -36,3 +192,51 @@ pub fn query_denom_admin(
     };
     Ok(deps.querier.query(&query.into())?)
 }
+
+pub fn query_denoms_from_creator(
+    deps: Deps<NeutronQuery>,
+    creator: impl Into<String>,
+) -> NeutronResult<DenomsFromCreatorResponse> {
+    let query = NeutronQuery::DenomsFromCreator {
+        creator: creator.into(),
+    };
+    Ok(deps.querier.query(&query.into())?)
+}
+
+/// Queries all the denoms created by **creator** along with their total supply and admin
+#[cfg(feature = "cosmwasm_1_1")]
+pub fn query_factory_denoms_info(
+    deps: Deps<NeutronQuery>,
+    creator: impl Into<String>,
+) -> NeutronResult<Vec<FactoryDenomInfo>> {
+    query_denoms_from_creator(deps, creator)?
+        .denoms
+        .into_iter()
+        .map(|denom| {
+            let supply = deps.querier.query_supply(denom.clone())?;
+            let admin = query_denom_admin(deps, denom.clone())?.admin;
+            Ok(FactoryDenomInfo {
+                denom,
+                supply,
+                admin,
+            })
+        })
+        .collect()
+}
+
+pub fn query_before_send_hook(
+    deps: Deps<NeutronQuery>,
+    denom: impl Into<String>,
+) -> NeutronResult<BeforeSendHookResponse> {
+    let query = NeutronQuery::BeforeSendHook {
+        denom: denom.into(),
+    };
+    Ok(deps.querier.query(&query.into())?)
+}
+
+pub fn query_token_factory_params(
+    deps: Deps<NeutronQuery>,
+) -> NeutronResult<TokenFactoryParamsResponse> {
+    let query = NeutronQuery::TokenFactoryParams {};
+    Ok(deps.querier.query(&query.into())?)
+}